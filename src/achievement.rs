@@ -0,0 +1,128 @@
+use crate::func::{is_clockwise, ScoreBreakdown};
+use serde::{Deserialize, Serialize};
+
+// 연속 기록 조건 확인을 위해 보관하는 최근 점수 개수
+const RECENT_SCORES: usize = 20;
+
+/// 업적 달성 조건
+#[derive(Clone, Copy, PartialEq)]
+pub enum Condition {
+    ScoreAtLeast(u32),                          // 한 라운드에서 이 점수 이상
+    StreakAbove { score: u32, rounds: usize },  // 연속 `rounds` 라운드 동안 `score`점 초과
+    FastRound { max_time: f64, min_score: u32 }, // `max_time` 밀리초 안에 `min_score`점 이상
+    DaysPlayed(usize),                          // 서로 다른 날짜에 플레이한 횟수
+    RoundsPlayed(usize),                        // 누적 라운드 수
+    Clockwise { min_score: u32 },               // 시계 방향으로 그려서 `min_score`점 이상
+}
+
+/// 업적 정의
+#[derive(PartialEq)]
+pub struct Achievement {
+    pub id: &'static str, // 저장용 고유 키 (바꾸면 기존 달성 기록이 사라짐)
+    pub title: &'static str,
+    pub description: &'static str,
+    pub condition: Condition,
+}
+
+/// 업적 목록 (새 업적은 여기에 추가)
+pub const ACHIEVEMENTS: [Achievement; 8] = [
+    Achievement {
+        id: "first-round",
+        title: "첫 트리",
+        description: "처음으로 트리를 그렸어요",
+        condition: Condition::RoundsPlayed(1),
+    },
+    Achievement {
+        id: "perfect",
+        title: "완벽한 트리",
+        description: "100점 달성",
+        condition: Condition::ScoreAtLeast(100),
+    },
+    Achievement {
+        id: "streak-90",
+        title: "흔들림 없는 손",
+        description: "5라운드 연속 90점 초과",
+        condition: Condition::StreakAbove { score: 90, rounds: 5 },
+    },
+    Achievement {
+        id: "speed-2s",
+        title: "번개손",
+        description: "2초 안에 80점 이상",
+        condition: Condition::FastRound { max_time: 2000.0, min_score: 80 },
+    },
+    Achievement {
+        id: "days-7",
+        title: "트리 장인",
+        description: "서로 다른 7일 동안 플레이",
+        condition: Condition::DaysPlayed(7),
+    },
+    Achievement {
+        id: "clockwise",
+        title: "거꾸로 그리기",
+        description: "시계 방향으로 그려서 60점 이상",
+        condition: Condition::Clockwise { min_score: 60 },
+    },
+    Achievement {
+        id: "rounds-50",
+        title: "꾸준함",
+        description: "트리 50번 그리기",
+        condition: Condition::RoundsPlayed(50),
+    },
+    Achievement {
+        id: "days-30",
+        title: "한 달의 트리",
+        description: "서로 다른 30일 동안 플레이",
+        condition: Condition::DaysPlayed(30),
+    },
+];
+
+/// 업적 진행 상황 (로컬 저장)
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AchievementProgress {
+    pub unlocked: Vec<String>,   // 달성한 업적 id
+    pub recent_scores: Vec<u32>, // 최근 라운드 점수 (오래된 순)
+    pub play_days: Vec<String>,  // 플레이한 날짜 ("YYYY-MM-DD")
+    pub rounds_played: usize,
+}
+
+impl AchievementProgress {
+    pub fn is_unlocked(&self, achievement: &Achievement) -> bool {
+        self.unlocked.iter().any(|id| id == achievement.id)
+    }
+
+    /// 라운드 결과를 반영하고 새로 달성한 업적 반환
+    pub fn record_round(&mut self, breakdown: &ScoreBreakdown, path: &[(f64, f64)], today: String) -> Vec<&'static Achievement> {
+        self.rounds_played += 1;
+        self.recent_scores.push(breakdown.score);
+        if self.recent_scores.len() > RECENT_SCORES {
+            self.recent_scores.remove(0);
+        }
+        if !self.play_days.contains(&today) {
+            self.play_days.push(today);
+        }
+
+        let newly_unlocked: Vec<&'static Achievement> = ACHIEVEMENTS
+            .iter()
+            .filter(|achievement| !self.is_unlocked(achievement) && self.is_met(&achievement.condition, breakdown, path))
+            .collect();
+        self.unlocked
+            .extend(newly_unlocked.iter().map(|achievement| achievement.id.to_string()));
+        newly_unlocked
+    }
+
+    fn is_met(&self, condition: &Condition, breakdown: &ScoreBreakdown, path: &[(f64, f64)]) -> bool {
+        match *condition {
+            Condition::ScoreAtLeast(score) => breakdown.score >= score,
+            Condition::StreakAbove { score, rounds } => {
+                self.recent_scores.len() >= rounds
+                    && self.recent_scores.iter().rev().take(rounds).all(|recent| *recent > score)
+            }
+            Condition::FastRound { max_time, min_score } => {
+                breakdown.score >= min_score && breakdown.time_used > 0.0 && breakdown.time_used < max_time
+            }
+            Condition::DaysPlayed(days) => self.play_days.len() >= days,
+            Condition::RoundsPlayed(rounds) => self.rounds_played >= rounds,
+            Condition::Clockwise { min_score } => breakdown.score >= min_score && is_clockwise(path),
+        }
+    }
+}
@@ -1,50 +1,253 @@
+use crate::achievement::{Achievement, AchievementProgress};
+use crate::assist::AssistSettings;
+use crate::campaign::{CampaignProgress, LEVELS};
+use crate::component::bracket_screen::{BracketHandoff, BracketScreen};
+use crate::component::drawing_board::LiveFeedback;
+use crate::component::compatibility_report::CompatibilityReport;
 use crate::component::drawing_screen::DrawingScreen;
+use crate::component::duel_screen::{DuelResult, DuelScreen};
+use crate::component::level_select_screen::LevelSelectScreen;
+use crate::component::party_screen::{PartyHandoff, PartySetup, PartyStandings};
 use crate::component::result_screen::ResultScreen;
 use crate::component::start_screen::StartScreen;
+use crate::component::stats_screen::StatsScreen;
+use crate::component::survival_screen::{RoundClear, SurvivalOver};
+use crate::component::trophy_screen::TrophyScreen;
+use crate::device::DeviceReport;
+use crate::duel::DuelSession;
 use crate::func::*;
+use crate::ghost::{self, GhostStroke};
 use crate::lottie::start_snow_animation;
+use crate::party::PartySession;
+use crate::pattern::Pattern;
+use crate::stats::DailyRecord;
+use crate::storage;
+use crate::stroke::{points, StrokeBuffer, StrokeSample};
+use crate::survival::{SurvivalRun, PASS_SCORE};
+use crate::tournament::{Bracket, MAX_ENTRANTS};
 use gloo::timers::callback::{Interval, Timeout};
-use wasm_bindgen::{JsValue, JsCast};
+use wasm_bindgen::JsCast;
 use wasm_bindgen::closure::Closure;
 use web_sys::{js_sys, window};
 use yew::prelude::*;
 
+const SCORE_TOLERANCE: f64 = 10.0; // 패턴 점 통과로 인정하는 거리
+
 pub struct TreeDrawingChallenge {
     last_position: Option<(f64, f64)>, // 마지막 위치 저장하여 원을 연결
-    pattern: Vec<(f64, f64)>,          // 트리 외곽 라인 패턴을 하나의 연속된 좌표로 저장
-    score: Option<u32>,
+    pattern: Pattern,                  // 트리 외곽 라인 패턴
+    difficulty: Difficulty,
+    breakdown: Option<ScoreBreakdown>, // 마지막 라운드 채점 결과
     timer: Option<Timeout>,
     countdown: Option<Interval>,
     remaining_time: f64,
-    svg_ref: NodeRef,
+    stroke: StrokeBuffer,              // 그리기 보드가 채우는 경로 버퍼
     is_drawing: bool,
     game_state: GameState, // 화면 상태 추가
-    is_mobile: Option<bool>,
-    result_path: Vec<(f64, f64)>,     // 사용자가 그린 경로
+    device: Option<DeviceReport>,      // 기기 호환성 보고서
+    result_path: Vec<StrokeSample>,   // 사용자가 그린 경로
+    show_tutorial: bool,               // 시범 영상 재생 여부
+    game_mode: GameMode,
+    time_mode: TimeMode,
+    draw_phase: DrawPhase,             // 그리기 화면 진행 단계
+    use_countdown: bool,               // 3-2-1 카운트다운 사용 여부
+    fail_out_of_bounds: bool,          // 그리는 영역을 벗어나면 바로 실패
+    assist: AssistSettings,            // 보조 모드 설정
+    pre_countdown: Option<Interval>,
+    campaign_level: Option<usize>,       // 캠페인으로 플레이 중인 레벨
+    campaign_progress: CampaignProgress,
+    survival: Option<SurvivalRun>,       // 서바이벌 모드 진행 상황
+    party: Option<PartySession>,         // 파티 모드 (돌려가며 플레이) 세션
+    draw_start_time: Option<f64>,        // 첫 터치 시각
+    time_used: f64,                      // 첫 터치부터 손을 뗄 때까지 걸린 시간
+    duel: Option<DuelSession>,           // 태블릿 듀얼 모드 세션
+    ghost: Option<GhostStroke>,          // 도전장 링크로 받은 친구의 기록
+    challenge_code: Option<String>,      // 마지막 라운드 기록으로 만든 도전장 코드
+    practice: bool,                      // 연습 모드 (타이머, 공유 없음)
+    tournament: Option<Bracket>,         // 토너먼트 대진표
+    tournament_player: Option<String>,   // 마지막으로 그린 토너먼트 참가자
+    achievements: AchievementProgress,
+    new_achievements: Vec<&'static Achievement>, // 마지막 라운드에서 새로 달성한 업적
+    daily_record: DailyRecord,           // 날짜별 최고 점수 (연속 플레이 기록)
+    use_haptics: bool,                   // 연습 중 허용 거리를 벗어나면 진동
+    is_landscape: bool,                  // 마지막으로 확인한 화면 방향
+    paused_at: Option<f64>,              // 라운드 중 화면 방향이 바뀌어 멈춘 시각
+    interrupted: bool,                   // 브라우저 제스처로 선이 끊긴 라운드
+    left_area: bool,                     // 영역 이탈 실패 규칙으로 끝난 라운드
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
 }
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "쉬움",
+            Difficulty::Normal => "보통",
+            Difficulty::Hard => "어려움",
+        }
+    }
+
+    /// 시작점과 진행 방향 안내 표시 여부
+    pub fn shows_direction_guide(&self) -> bool {
+        !matches!(self, Difficulty::Hard)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum GameMode {
+    Classic,
+    Memory { preview_ms: u32 }, // 가이드를 미리 보여준 뒤 숨기고 기억으로 그리기
+}
+
+impl GameMode {
+    pub const MEMORY_PREVIEW_OPTIONS: [u32; 3] = [1000, 2000, 3000];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "클래식",
+            GameMode::Memory { .. } => "기억 모드",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum TimeMode {
+    Blitz,
+    Classic,
+    Relaxed,
+    Zen, // 시간 제한 없이 손을 뗄 때 종료
+}
+
+impl TimeMode {
+    pub const ALL: [TimeMode; 4] = [TimeMode::Blitz, TimeMode::Classic, TimeMode::Relaxed, TimeMode::Zen];
+
+    /// 제한 시간 (밀리초), 젠 모드는 제한 없음
+    pub fn duration(&self) -> Option<f64> {
+        match self {
+            TimeMode::Blitz => Some(3000.0),
+            TimeMode::Classic => Some(5000.0),
+            TimeMode::Relaxed => Some(10000.0),
+            TimeMode::Zen => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeMode::Blitz => "3초",
+            TimeMode::Classic => "5초",
+            TimeMode::Relaxed => "10초",
+            TimeMode::Zen => "무제한",
+        }
+    }
+
+    /// 시작 화면 안내 문구
+    pub fn description(&self) -> &'static str {
+        match self {
+            TimeMode::Blitz => "3초 안에 트리를 그려보세요!",
+            TimeMode::Classic => "5초 안에 트리를 그려보세요!",
+            TimeMode::Relaxed => "10초 안에 트리를 그려보세요!",
+            TimeMode::Zen => "천천히 트리를 그려보세요!",
+        }
+    }
+
+    /// 타이머 시작 시 표시할 시간
+    pub fn initial_time(&self) -> f64 {
+        self.duration().unwrap_or(0.0)
+    }
+}
+
+/// 그리기 화면 진행 단계
+#[derive(Clone, Copy, PartialEq)]
+pub enum DrawPhase {
+    Preview,        // 기억 모드 가이드 미리보기
+    Countdown(u32), // 3-2-1 카운트다운
+    Ready,          // 첫 터치 대기 (타이머 시작 전)
+    Drawing,        // 첫 터치 이후 타이머 진행 중
+}
+
+impl DrawPhase {
+    /// 터치 입력을 받을 수 있는 단계인지 확인
+    pub fn accepts_input(&self) -> bool {
+        matches!(self, DrawPhase::Ready | DrawPhase::Drawing)
+    }
+}
+
 pub enum GameState {
     StartScreen,
     DrawingScreen,
     ResultScreen,
+    LevelSelect,
+    RoundClear,
+    SurvivalOver,
+    PartySetup,
+    PartyHandoff,
+    PartyStandings,
+    TournamentSetup,
+    Bracket,
+    TournamentHandoff,
+    Trophies,
+    Stats,
+    DuelScreen,
+    DuelResult,
     UnsupportedDevice,
-    LandscapeMode, // 새로운 메시지 추가
 }
 
 pub enum Msg {
     StartGame,
     StartDraw,
     StopDraw,
+    CancelDraw,
+    LeaveArea,
     CalculateScore,
     UpdateTime(f64),
     DetectDevice,
-    SetResultPath(Vec<(f64, f64)>),
     DetectOrientation, 
+    SelectDifficulty(Difficulty),
+    ShowTutorial,
+    FinishTutorial,
+    SelectGameMode(GameMode),
+    EndPreview,
+    SelectTimeMode(TimeMode),
+    ToggleCountdown,
+    ToggleBoundsRule,
+    UpdateAssist(AssistSettings),
+    CountdownTick,
+    OpenLevelSelect,
+    StartLevel(usize),
+    BackToStart,
+    StartSurvival,
+    NextRound,
+    OpenPartySetup,
+    StartParty(Vec<String>),
+    NextPlayer,
+    StartDuel,
+    DuelStartDraw(usize),
+    DuelStrokeEnd(usize),
+    DuelStrokeCancel(usize),
+    DuelLeaveArea(usize),
+    ClearGhost,
+    StartPractice,
+    ToggleHaptics,
+    OpenTournament,
+    StartTournament(Vec<String>),
+    NewTournament,
+    PlayMatch,
+    OpenTrophies,
+    OpenStats,
+    Resume,
 }
 
 impl TreeDrawingChallenge {
 
     /// 가로 모드 감지
-    fn detect_orientation(&self) -> bool {
+    fn detect_orientation() -> bool {
         if let Some(window) = window() {
             let width = window.inner_width().unwrap().as_f64().unwrap_or(0.0);
             let height = window.inner_height().unwrap().as_f64().unwrap_or(0.0);
@@ -57,21 +260,30 @@ impl TreeDrawingChallenge {
     }
 
     /// 타이머 시작
-    fn start_timer(&mut self, ctx: &Context<Self>, duration: f64) {
-        let start_time = Self::get_now();
+    ///
+    /// 제한 시간이 없으면 경과 시간을 표시하고, 손을 뗄 때까지 종료하지 않음.
+    /// 멈췄다가 다시 시작할 때는 이미 지난 시간(`elapsed`)부터 이어서 잽니다.
+    fn start_timer(&mut self, ctx: &Context<Self>, duration: Option<f64>, elapsed: f64) {
+        let start_time = Self::get_now() - elapsed;
 
-        self.remaining_time = duration;
+        self.remaining_time = duration.map_or(elapsed, |duration| (duration - elapsed).max(0.0));
 
         let link = ctx.link().clone();
         self.countdown = Some(Interval::new(100, move || {
             let now = Self::get_now();
             let elapsed = now - start_time;
-            let remaining = (duration - elapsed).max(0.0);
 
-            link.send_message(Msg::UpdateTime(remaining));
+            match duration {
+                Some(duration) => {
+                    let remaining = (duration - elapsed).max(0.0);
 
-            if remaining <= 0.0 {
-                link.send_message(Msg::StopDraw);
+                    link.send_message(Msg::UpdateTime(remaining));
+
+                    if remaining <= 0.0 {
+                        link.send_message(Msg::StopDraw);
+                    }
+                }
+                None => link.send_message(Msg::UpdateTime(elapsed)),
             }
         }));
     }
@@ -85,9 +297,106 @@ impl TreeDrawingChallenge {
             .now()
     }
 
+    /// 화면 방향이 바뀌었으면 기록하고, 그리는 중이었다면 라운드를 멈춤
+    ///
+    /// 새로 멈췄을 때만 `true`를 반환합니다.
+    fn pause_on_rotation(&mut self) -> bool {
+        let is_landscape = Self::detect_orientation();
+        if is_landscape == self.is_landscape {
+            return false;
+        }
+        self.is_landscape = is_landscape;
+
+        let drawing = matches!(self.game_state, GameState::DrawingScreen | GameState::DuelScreen)
+            && self.draw_phase == DrawPhase::Drawing;
+        if !drawing || self.paused_at.is_some() {
+            return false;
+        }
+        self.countdown = None;
+        self.paused_at = Some(Self::get_now());
+        true
+    }
+
     fn stop_timer(&mut self) {
         self.timer = None;
         self.countdown = None;
+        self.pre_countdown = None;
+    }
+
+    /// 마지막 라운드 점수
+    fn score(&self) -> u32 {
+        self.breakdown.as_ref().map_or(0, |breakdown| breakdown.score)
+    }
+
+    /// 특수 모드(캠페인, 서바이벌, 파티) 종료
+    ///
+    /// 서바이벌과 캠페인이 바꿔 둔 패턴도 기본 트리로 되돌립니다.
+    fn leave_modes(&mut self) {
+        self.pattern = Pattern::tree();
+        self.campaign_level = None;
+        self.survival = None;
+        self.party = None;
+        self.duel = None;
+        self.practice = false;
+        self.tournament = None;
+    }
+
+    /// 친구 고스트와 함께 달리는 중인지 (일반 모드에서만)
+    fn racing_ghost(&self) -> Option<&GhostStroke> {
+        let in_mode = self.campaign_level.is_some()
+            || self.survival.is_some()
+            || self.party.is_some()
+            || self.duel.is_some()
+            || self.practice
+            || self.tournament.is_some();
+        self.ghost.as_ref().filter(|_| !in_mode)
+    }
+
+    /// 현재 라운드의 제한 시간 (서바이벌 모드는 라운드 규칙 사용, 연습 모드는 제한 없음)
+    ///
+    /// 보조 모드에서는 설정한 배율만큼 늘어납니다.
+    fn current_duration(&self) -> Option<f64> {
+        let duration = match &self.survival {
+            Some(run) => Some(run.rules().duration),
+            None if self.practice => None,
+            None => self.current_time_mode().duration(),
+        };
+        duration.map(|duration| self.assist.scale_duration(duration))
+    }
+
+    /// 현재 라운드의 난이도 (캠페인은 레벨 규칙, 그 외에는 시작 화면에서 고른 난이도)
+    fn current_difficulty(&self) -> Difficulty {
+        self.campaign_level
+            .and_then(|index| LEVELS.get(index))
+            .map_or(self.difficulty, |level| level.difficulty)
+    }
+
+    /// 현재 라운드의 제한 시간 모드 (캠페인은 레벨 규칙)
+    fn current_time_mode(&self) -> TimeMode {
+        self.campaign_level
+            .and_then(|index| LEVELS.get(index))
+            .map_or(self.time_mode, |level| level.time_mode)
+    }
+
+    /// 현재 라운드의 채점 허용 거리
+    fn current_tolerance(&self) -> f64 {
+        match &self.survival {
+            Some(run) => run.rules().tolerance,
+            None => SCORE_TOLERANCE,
+        }
+    }
+
+    /// 미리보기가 끝난 뒤 카운트다운 또는 첫 터치 대기로 전환
+    fn begin_ready(&mut self, ctx: &Context<Self>) {
+        if self.use_countdown {
+            self.draw_phase = DrawPhase::Countdown(3);
+            let link = ctx.link().clone();
+            self.pre_countdown = Some(Interval::new(1000, move || {
+                link.send_message(Msg::CountdownTick);
+            }));
+        } else {
+            self.draw_phase = DrawPhase::Ready;
+        }
     }
 }
 
@@ -101,68 +410,6 @@ impl Component for TreeDrawingChallenge {
 
     fn create(_ctx: &Context<Self>) -> Self {
 
-        let points = vec![
-            (130.0, 0.0),   // 트리 꼭대기
-            (120.0, 16.0),
-            (110.0, 32.0),
-            (100.0, 48.0),
-            (90.0, 64.0),
-            (80.0, 80.0),
-            (70.0, 96.0),
-            (60.0, 112.0),
-            (50.0, 128.0),
-            (40.0, 144.0),
-            (30.0, 160.0),  // 오른쪽
-            (40.0, 160.0),
-            (50.0, 160.0),
-            (60.0, 160.0),
-            (70.0, 160.0),
-            (70.0, 160.0),
-            (63.0, 173.0),
-            (56.0, 186.0),
-            (49.0, 199.0),
-            (42.0, 212.0),
-            (35.0, 225.0),
-            (28.0, 238.0),
-            (21.0, 251.0),
-            (14.0, 264.0),
-            (7.0, 277.0),
-            (0.0, 290.0),  // 밑바닥
-            (25.0, 290.0),
-            (50.0, 290.0),
-            (75.0, 290.0),
-            (100.0, 290.0),
-            (125.0, 290.0),
-            (150.0, 290.0),
-            (175.0, 290.0),
-            (200.0, 290.0),
-            (225.0, 290.0),
-            (250.0, 290.0),
-            (243.0, 277.0),
-            (236.0, 264.0),
-            (229.0, 251.0),
-            (222.0, 238.0),
-            (215.0, 225.0),
-            (208.0, 212.0),
-            (201.0, 199.0),
-            (194.0, 186.0),
-            (187.0, 173.0),
-            (180.0, 160.0), // 왼쪽
-            (190.0, 160.0),
-            (200.0, 160.0),
-            (210.0, 160.0),
-            (220.0, 160.0), // 트리 꼭대기
-            (211.0, 144.0), 
-            (202.0, 128.0), 
-            (193.0, 112.0), 
-            (184.0, 96.0), 
-            (175.0, 80.0), 
-            (166.0, 64.0), 
-            (157.0, 48.0), 
-            (148.0, 32.0), 
-            (139.0, 16.0), 
-            (130.0, 0.0), 
-        ];
         let link = _ctx.link().clone();
         link.send_message(Msg::DetectDevice);
 
@@ -180,94 +427,483 @@ impl Component for TreeDrawingChallenge {
 
         Self {
             last_position: None,
-            pattern: points,
-            score: None,
+            pattern: Pattern::tree(),
+            difficulty: Difficulty::Normal,
+            breakdown: None,
             timer: None,
             countdown: None,
-            remaining_time: 5000.0,
-            svg_ref: NodeRef::default(),
+            remaining_time: TimeMode::Classic.initial_time(),
+            stroke: StrokeBuffer::default(),
             is_drawing: false,
             game_state: GameState::StartScreen,
-            is_mobile: None,
+            device: None,
             result_path: vec![],
+            show_tutorial: !storage::is_tutorial_seen(), // 첫 방문에만 자동 재생
+            game_mode: GameMode::Classic,
+            time_mode: TimeMode::Classic,
+            draw_phase: DrawPhase::Ready,
+            use_countdown: true,
+            fail_out_of_bounds: false,
+            assist: storage::load_assist_settings(),
+            pre_countdown: None,
+            campaign_level: None,
+            campaign_progress: storage::load_campaign_progress(),
+            survival: None,
+            party: None,
+            draw_start_time: None,
+            time_used: 0.0,
+            duel: None,
+            ghost: ghost::load_from_location(),
+            challenge_code: None,
+            practice: false,
+            use_haptics: true,
+            is_landscape: Self::detect_orientation(),
+            paused_at: None,
+            interrupted: false,
+            left_area: false,
+            tournament: None,
+            tournament_player: None,
+            achievements: storage::load_achievements(),
+            new_achievements: vec![],
+            daily_record: storage::load_daily_record(),
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::DetectOrientation => {
-                // 방향 감지 및 상태 변경
-                if self.detect_orientation() {
-                    self.game_state = GameState::LandscapeMode;
-                } else if self.is_mobile.unwrap_or(false) {
-                    self.game_state = GameState::StartScreen;
+                // 창 크기가 바뀌었으므로 다시 확인 (화면 배치는 CSS가 방향에 맞춰 바꿈)
+                self.device = Some(DeviceReport::detect());
+                self.pause_on_rotation();
+                true
+            }
+            Msg::Resume => {
+                // 멈춰 있던 시간만큼 시작 시각을 미뤄 걸린 시간에서 제외
+                if let Some(paused_at) = self.paused_at.take() {
+                    let now = Self::get_now();
+                    if let Some(start_time) = self.draw_start_time.as_mut() {
+                        *start_time += now - paused_at;
+                    }
+                    if let Some(duel) = self.duel.as_mut() {
+                        duel.resume(now - paused_at);
+                    }
+                    let elapsed = self.draw_start_time.map_or(0.0, |start_time| now - start_time);
+                    self.start_timer(ctx, self.current_duration(), elapsed);
                 }
                 true
             }
             Msg::DetectDevice => {
-                if let Some(window) = window() {
-                    let navigator = window.navigator();
-                    let user_agent = navigator.user_agent().unwrap_or_default();
-                    web_sys::console::log_1(&format!("User-Agent: {}", user_agent).into());
-
-                    // 터치 디바이스 확인
-                    let has_touch_event =
-                        js_sys::Reflect::has(&window, &JsValue::from_str("ontouchstart"))
-                            .unwrap_or(false);
-                    let is_touch_device = has_touch_event || navigator.max_touch_points() > 0;
-
-                    // 플랫폼 확인
-                    let platform = navigator.platform().unwrap_or_default();
-                    let is_ipad = platform.contains("iPad") // iPad 플랫폼 명시적 감지
-                        || (platform == "MacIntel" && is_touch_device); // 터치 가능한 Mac은 iPadOS일 가능성
-
-                    // 모바일 키워드 확인
-                    let is_mobile = user_agent.contains("iPhone")
-                        || user_agent.contains("Android")
-                        || user_agent.contains("Mobile")
-                        || is_ipad;
-
-                    self.is_mobile = Some(is_mobile && is_touch_device);
-
-                    // 모바일이 아니면 UnsupportedDevice 상태로 변경
-                    if !self.is_mobile.unwrap_or(false) {
-                        self.game_state = GameState::UnsupportedDevice;
+                let device = DeviceReport::detect();
+                web_sys::console::log_1(&format!("Device: {:?}", device).into());
+
+                // 필수 기능(포인터 입력, 화면 크기)이 없으면 이유를 설명하는 화면으로
+                if !device.is_supported() {
+                    self.game_state = GameState::UnsupportedDevice;
+                }
+                self.device = Some(device);
+                true
+            }
+            Msg::StartSurvival => {
+                let run = SurvivalRun::new((js_sys::Math::random() * u32::MAX as f64) as u32);
+                self.leave_modes();
+                self.pattern = run.rules().pattern;
+                self.survival = Some(run);
+                self.game_mode = GameMode::Classic;
+                ctx.link().send_message(Msg::StartGame);
+                true
+            }
+            Msg::NextRound => {
+                self.timer = None;
+                ctx.link().send_message(Msg::StartGame);
+                false
+            }
+            Msg::StartDuel => {
+                self.leave_modes();
+                self.duel = Some(DuelSession::default());
+                self.game_mode = GameMode::Classic;
+                ctx.link().send_message(Msg::StartGame);
+                true
+            }
+            Msg::DuelStartDraw(player) => {
+                let now = Self::get_now();
+                if let Some(duel) = self.duel.as_mut() {
+                    duel.start(player, now);
+                }
+                // 먼저 터치한 사람 기준으로 공용 타이머 시작
+                ctx.link().send_message(Msg::StartDraw);
+                false
+            }
+            Msg::DuelStrokeEnd(player) => {
+                // 화면을 돌리느라 손을 뗀 경우에는 끝내지 않고 멈춤
+                if self.paused_at.is_some() || self.pause_on_rotation() {
+                    return true;
+                }
+                let now = Self::get_now();
+                if let Some(duel) = self.duel.as_mut() {
+                    duel.finish(player, now);
+                    if duel.all_finished() {
+                        ctx.link().send_message(Msg::StopDraw);
+                    }
+                }
+                true
+            }
+            Msg::DuelStrokeCancel(player) => {
+                if self.paused_at.is_some() || self.pause_on_rotation() {
+                    return true;
+                }
+                if let Some(duel) = self.duel.as_mut() {
+                    duel.interrupted[player] = true;
+                }
+                ctx.link().send_message(Msg::DuelStrokeEnd(player));
+                false
+            }
+            Msg::DuelLeaveArea(player) => {
+                if self.paused_at.is_some() || self.pause_on_rotation() {
+                    return true;
+                }
+                if let Some(duel) = self.duel.as_mut() {
+                    duel.left_area[player] = true;
+                }
+                ctx.link().send_message(Msg::DuelStrokeEnd(player));
+                false
+            }
+            Msg::OpenPartySetup => {
+                self.leave_modes();
+                self.game_state = GameState::PartySetup;
+                true
+            }
+            Msg::StartParty(names) => {
+                self.leave_modes();
+                self.party = Some(PartySession::new(names));
+                self.game_state = GameState::PartyHandoff;
+                true
+            }
+            Msg::NextPlayer => {
+                if let Some(party) = self.party.as_mut() {
+                    self.game_state = if party.advance() {
+                        GameState::PartyHandoff
+                    } else {
+                        GameState::PartyStandings
+                    };
+                }
+                true
+            }
+            Msg::OpenLevelSelect => {
+                self.leave_modes();
+                self.game_state = GameState::LevelSelect;
+                true
+            }
+            Msg::StartLevel(index) => {
+                if let Some(level) = LEVELS.get(index) {
+                    if self.campaign_progress.is_unlocked(index) {
+                        self.leave_modes();
+                        self.campaign_level = Some(index);
+                        self.pattern = level.pattern();
+                        self.game_mode = GameMode::Classic;
+                        ctx.link().send_message(Msg::StartGame);
                     }
                 }
                 true
             }
+            Msg::OpenStats => {
+                self.game_state = GameState::Stats;
+                true
+            }
+            Msg::OpenTrophies => {
+                self.game_state = GameState::Trophies;
+                true
+            }
+            Msg::OpenTournament => {
+                // 새로고침 전에 진행하던 토너먼트가 있으면 이어서
+                self.leave_modes();
+                self.tournament = storage::load_tournament();
+                self.game_state = if self.tournament.is_some() {
+                    GameState::Bracket
+                } else {
+                    GameState::TournamentSetup
+                };
+                true
+            }
+            Msg::StartTournament(names) => {
+                let bracket = Bracket::new(names);
+                storage::save_tournament(&bracket);
+                self.leave_modes();
+                self.tournament = Some(bracket);
+                self.game_state = GameState::Bracket;
+                true
+            }
+            Msg::NewTournament => {
+                storage::clear_tournament();
+                self.leave_modes();
+                self.game_state = GameState::TournamentSetup;
+                true
+            }
+            Msg::PlayMatch => {
+                if self.tournament.as_ref().and_then(Bracket::current_match).is_some() {
+                    // 한 경기의 두 차례는 같은 패턴으로 진행
+                    self.pattern = Pattern::tree();
+                    self.game_mode = GameMode::Classic;
+                    self.game_state = GameState::TournamentHandoff;
+                } else {
+                    self.game_state = GameState::Bracket;
+                }
+                true
+            }
+            Msg::StartPractice => {
+                self.leave_modes();
+                self.practice = true;
+                self.game_mode = GameMode::Classic;
+                ctx.link().send_message(Msg::StartGame);
+                true
+            }
+            Msg::ToggleHaptics => {
+                self.use_haptics = !self.use_haptics;
+                true
+            }
+            Msg::ClearGhost => {
+                self.ghost = None;
+                true
+            }
+            Msg::BackToStart => {
+                self.leave_modes();
+                self.game_state = GameState::StartScreen;
+                true
+            }
+            Msg::SelectTimeMode(time_mode) => {
+                self.time_mode = time_mode;
+                self.remaining_time = time_mode.initial_time();
+                true
+            }
+            Msg::SelectGameMode(game_mode) => {
+                self.game_mode = game_mode;
+                true
+            }
+            Msg::SelectDifficulty(difficulty) => {
+                self.difficulty = difficulty;
+                true
+            }
+            Msg::ShowTutorial => {
+                self.show_tutorial = true;
+                true
+            }
+            Msg::FinishTutorial => {
+                self.show_tutorial = false;
+                storage::set_tutorial_seen();
+                true
+            }
             Msg::StartGame => {
+                // 시범 영상을 건너뛰고 바로 시작해도 다시 보여주지 않음
+                if self.show_tutorial {
+                    self.show_tutorial = false;
+                    storage::set_tutorial_seen();
+                }
                 self.result_path.clear();
+                self.stroke.clear();
+                if let Some(duel) = self.duel.as_mut() {
+                    duel.reset();
+                }
                 self.last_position = None;
-                self.score = None;
-                self.remaining_time = 5000.0;
+                self.breakdown = None;
+                self.draw_start_time = None;
+                self.paused_at = None;
+                self.interrupted = false;
+                self.left_area = false;
+                self.time_used = 0.0;
+                self.challenge_code = None;
+                self.new_achievements.clear();
+                if self.racing_ghost().is_some() {
+                    self.pattern = Pattern::tree(); // 고스트는 기본 트리에서 기록됨
+                }
+                self.remaining_time = self.current_duration().unwrap_or(0.0);
                 self.is_drawing = true;
-                self.game_state = GameState::DrawingScreen;
-                self.start_timer(ctx, 5000.0);
+                self.game_state = if self.duel.is_some() {
+                    GameState::DuelScreen
+                } else {
+                    GameState::DrawingScreen
+                };
+
+                // 타이머는 첫 터치(Msg::StartDraw)에서 시작
+                match self.game_mode {
+                    GameMode::Classic => self.begin_ready(ctx),
+                    GameMode::Memory { preview_ms } => {
+                        self.draw_phase = DrawPhase::Preview;
+                        let link = ctx.link().clone();
+                        self.timer = Some(Timeout::new(preview_ms, move || {
+                            link.send_message(Msg::EndPreview);
+                        }));
+                    }
+                }
+                true
+            }
+            Msg::EndPreview => {
+                self.timer = None;
+                self.begin_ready(ctx);
+                true
+            }
+            Msg::ToggleCountdown => {
+                self.use_countdown = !self.use_countdown;
+                true
+            }
+            Msg::ToggleBoundsRule => {
+                self.fail_out_of_bounds = !self.fail_out_of_bounds;
+                true
+            }
+            Msg::UpdateAssist(settings) => {
+                self.assist = settings;
+                storage::save_assist_settings(&settings);
+                true
+            }
+            Msg::CountdownTick => {
+                self.draw_phase = match self.draw_phase {
+                    DrawPhase::Countdown(count) if count > 1 => DrawPhase::Countdown(count - 1),
+                    _ => {
+                        self.pre_countdown = None;
+                        DrawPhase::Ready
+                    }
+                };
                 true
             }
             Msg::StartDraw => {
+                // 실제로 처음 터치한 순간부터 시간 측정
+                if self.draw_phase == DrawPhase::Ready {
+                    self.draw_phase = DrawPhase::Drawing;
+                    self.draw_start_time = Some(Self::get_now());
+                    self.start_timer(ctx, self.current_duration(), 0.0);
+                }
                 true
             }
+            Msg::CancelDraw => {
+                // 화면 회전으로 취소된 터치는 멈춤으로 처리하고, 그 외에는 끊긴 라운드로 표시
+                if self.paused_at.is_some() || self.pause_on_rotation() {
+                    return true;
+                }
+                self.interrupted = true;
+                ctx.link().send_message(Msg::StopDraw);
+                false
+            }
+            Msg::LeaveArea => {
+                // 화면을 돌리다 영역을 벗어난 경우에는 실패로 치지 않고 멈춤
+                if self.paused_at.is_some() || self.pause_on_rotation() {
+                    return true;
+                }
+                self.left_area = true;
+                ctx.link().send_message(Msg::StopDraw);
+                false
+            }
             Msg::StopDraw => {
+                // 화면을 돌리느라 손을 뗀 경우에는 끝내지 않고 멈춤
+                if self.paused_at.is_some() || self.pause_on_rotation() {
+                    return true;
+                }
                 self.is_drawing = false;
                 self.stop_timer();
-                self.game_state = GameState::ResultScreen;
+                self.time_used = self
+                    .draw_start_time
+                    .map_or(0.0, |start_time| Self::get_now() - start_time);
+                self.result_path = self.stroke.snapshot();
+
+                // 듀얼 모드: 두 사람 모두 채점 후 결과 화면으로
+                if let Some(duel) = self.duel.as_mut() {
+                    let now = Self::get_now();
+                    for player in 0..2 {
+                        duel.finish(player, now);
+                        duel.paths[player] = duel.strokes[player].snapshot();
+                        let mut breakdown = score_breakdown(
+                            &duel.paths[player],
+                            &self.pattern.points,
+                            SCORE_TOLERANCE,
+                            duel.time_used[player],
+                        );
+                        breakdown.interrupted = duel.interrupted[player];
+                        breakdown.left_play_area = duel.left_area[player];
+                        breakdown.assisted = self.assist.enabled;
+                        if duel.left_area[player] {
+                            breakdown.score = 0;
+                        }
+                        duel.results[player] = Some(breakdown);
+                    }
+                    self.game_state = GameState::DuelResult;
+                    return true;
+                }
+
+                // 서바이벌 모드는 채점 결과에 따라 다음 화면 결정
+                if self.survival.is_none() {
+                    self.game_state = GameState::ResultScreen;
+                }
                 ctx.link().send_message(Msg::CalculateScore);
                 true
             }
-            Msg::SetResultPath(path) => {
-                self.result_path= path;
-                true
-            }
             Msg::CalculateScore => {
-                self.score = Some(calculate_score(&self.result_path, &self.pattern, 10.0));
+                let mut breakdown = score_breakdown(
+                    &self.result_path,
+                    &self.pattern.points,
+                    self.current_tolerance(),
+                    self.time_used,
+                );
+                breakdown.interrupted = self.interrupted;
+                breakdown.left_play_area = self.left_area;
+                breakdown.assisted = self.assist.enabled;
+                if self.left_area {
+                    breakdown.score = 0;
+                }
+                let score = breakdown.score;
+
+                // 업적 확인과 오늘 기록 저장 (연습, 파티, 토너먼트 라운드 제외)
+                let personal_round = !self.practice && self.party.is_none() && self.tournament.is_none();
+                if personal_round {
+                    let today = today();
+                    self.new_achievements = self.achievements.record_round(&breakdown, &points(&self.result_path), today.clone());
+                    storage::save_achievements(&self.achievements);
+                    self.daily_record.record(today, score);
+                    storage::save_daily_record(&self.daily_record);
+                }
+
+                // 파티 모드: 현재 플레이어 결과 기록
+                if let Some(party) = self.party.as_mut() {
+                    party.record(breakdown.clone());
+                }
+
+                // 토너먼트: 현재 차례 결과 기록 후 대진표 저장
+                if let Some(bracket) = self.tournament.as_mut() {
+                    self.tournament_player = bracket.current_player().map(String::from);
+                    bracket.record(breakdown.clone());
+                    storage::save_tournament(bracket);
+                }
+                self.breakdown = Some(breakdown);
+
+                // 기본 트리 기록은 도전장 링크에 담을 고스트로 변환
+                if self.pattern == Pattern::tree() {
+                    self.challenge_code = Some(GhostStroke::new(&self.result_path, score).encode());
+                }
+
+                // 서바이벌 모드: 통과하면 곧바로 다음 라운드, 실패하면 종료
+                if let Some(run) = self.survival.as_mut() {
+                    if score >= PASS_SCORE {
+                        run.advance(score);
+                        self.pattern = run.rules().pattern;
+                        self.game_state = GameState::RoundClear;
+
+                        let link = ctx.link().clone();
+                        self.timer = Some(Timeout::new(1500, move || {
+                            link.send_message(Msg::NextRound);
+                        }));
+                    } else {
+                        self.game_state = GameState::SurvivalOver;
+                    }
+                }
+
+                // 캠페인 레벨 최고 점수 기록
+                if let Some(level) = self.campaign_level {
+                    if self.campaign_progress.record(level, score) {
+                        storage::save_campaign_progress(&self.campaign_progress);
+                    }
+                }
                 true
             }
             Msg::UpdateTime(remaining) => {
-                self.remaining_time = remaining; // 남은 시간 직접 설정
+                self.remaining_time = remaining; // 남은 시간 직접 설정 (젠 모드는 경과 시간)
 
-                if self.remaining_time <= 0.0 {
+                if self.current_duration().is_some() && self.remaining_time <= 0.0 {
                     ctx.link().send_message(Msg::StopDraw);
                 }
                 true
@@ -295,7 +931,38 @@ impl Component for TreeDrawingChallenge {
                     match self.game_state {
                         GameState::StartScreen => {
                             let start_game = ctx.link().callback(|_| Msg::StartGame);
-                            html! { <StartScreen on_start={start_game} /> }
+                            let select_difficulty = ctx.link().callback(Msg::SelectDifficulty);
+                            html! {
+                                <StartScreen
+                                    on_start={start_game}
+                                    difficulty={self.difficulty}
+                                    on_select_difficulty={select_difficulty}
+                                    pattern={self.pattern.clone()}
+                                    game_mode={self.game_mode}
+                                    on_select_game_mode={ctx.link().callback(Msg::SelectGameMode)}
+                                    time_mode={self.time_mode}
+                                    on_select_time_mode={ctx.link().callback(Msg::SelectTimeMode)}
+                                    use_countdown={self.use_countdown}
+                                    on_toggle_countdown={ctx.link().callback(|_| Msg::ToggleCountdown)}
+                                    fail_out_of_bounds={self.fail_out_of_bounds}
+                                    on_toggle_bounds_rule={ctx.link().callback(|_| Msg::ToggleBoundsRule)}
+                                    assist={self.assist}
+                                    on_change_assist={ctx.link().callback(Msg::UpdateAssist)}
+                                    show_tutorial={self.show_tutorial}
+                                    on_show_tutorial={ctx.link().callback(|_| Msg::ShowTutorial)}
+                                    on_tutorial_finish={ctx.link().callback(|_| Msg::FinishTutorial)}
+                                    on_open_campaign={ctx.link().callback(|_| Msg::OpenLevelSelect)}
+                                    on_start_survival={ctx.link().callback(|_| Msg::StartSurvival)}
+                                    on_open_party={ctx.link().callback(|_| Msg::OpenPartySetup)}
+                                    on_start_practice={ctx.link().callback(|_| Msg::StartPractice)}
+                                    on_open_tournament={ctx.link().callback(|_| Msg::OpenTournament)}
+                                    on_open_trophies={ctx.link().callback(|_| Msg::OpenTrophies)}
+                                    on_open_stats={ctx.link().callback(|_| Msg::OpenStats)}
+                                    on_start_duel={self.device.as_ref().is_some_and(DeviceReport::supports_duel).then(|| ctx.link().callback(|_| Msg::StartDuel))}
+                                    ghost_score={self.ghost.as_ref().map(|ghost| ghost.score)}
+                                    on_clear_ghost={ctx.link().callback(|_| Msg::ClearGhost)}
+                                />
+                            }
                         }
                         GameState::DrawingScreen => {
                             let start_draw = ctx.link().callback(|_| Msg::StartDraw);
@@ -304,42 +971,251 @@ impl Component for TreeDrawingChallenge {
                             html! {
                                 <DrawingScreen
                                     remaining_time={self.remaining_time}
-                                    svg_ref={self.svg_ref.clone()}
+                                    stroke={self.stroke.clone()}
+                                    pattern={self.pattern.clone()}
+                                    show_direction_guide={self.current_difficulty().shows_direction_guide()}
+                                    show_guide={self.game_mode == GameMode::Classic || self.draw_phase == DrawPhase::Preview}
+                                    draw_phase={self.draw_phase}
+                                    round={self.survival.as_ref().map(|run| run.round + 1)}
+                                    ghost={self.racing_ghost().cloned()}
+                                    feedback={self.practice.then(|| LiveFeedback {
+                                        tolerance: self.current_tolerance(),
+                                        haptics: self.use_haptics,
+                                    })}
+                                    on_toggle_haptics={self.practice.then(|| ctx.link().callback(|_| Msg::ToggleHaptics))}
+                                    paused={self.paused_at.is_some()}
+                                    on_resume={ctx.link().callback(|_| Msg::Resume)}
                                     on_start_draw={start_draw}
                                     on_touch_end={stop_draw.clone()}
-                                    result_path={ctx.link().callback(|path: Vec<(f64, f64)>| Msg::SetResultPath(path))}
+                                    on_touch_cancel={ctx.link().callback(|_| Msg::CancelDraw)}
+                                    on_leave_area={(self.fail_out_of_bounds && !self.practice).then(|| ctx.link().callback(|_| Msg::LeaveArea))}
+                                    assist={self.assist}
                                 />
                             }
                         }
                         GameState::ResultScreen => {
                             let retry = ctx.link().callback(|_| Msg::StartGame);
 
+                            // 캠페인 목표 달성 시 다음 레벨, 파티 모드는 다음 플레이어 버튼 표시
+                            let mut title = None;
+                            let mut next = None;
+                            if let Some(level) = self.campaign_level {
+                                let target = LEVELS[level].target_score;
+                                let cleared = self.score() >= target;
+                                title = Some(if cleared {
+                                    format!("목표 {}점 달성! 🎉", target)
+                                } else {
+                                    format!("목표 {}점에 도전해보세요!", target)
+                                });
+                                // 이번 기록이 목표에 못 미치면 다시 도전하기만 표시
+                                next = Some(level + 1)
+                                    .filter(|next| cleared && *next < LEVELS.len() && self.campaign_progress.is_unlocked(*next))
+                                    .map(|next| ("다음 레벨 ▶", ctx.link().callback(move |_| Msg::StartLevel(next))));
+                            }
+                            if let Some(party) = &self.party {
+                                title = party.current_player().map(|player| format!("{}님의 결과", player.name));
+                                let is_last = party.current + 1 >= party.players.len();
+                                let label = if is_last { "최종 순위 보기 ▶" } else { "다음 플레이어 ▶" };
+                                next = Some((label, ctx.link().callback(|_| Msg::NextPlayer)));
+                            }
+                            if let Some(bracket) = &self.tournament {
+                                title = self.tournament_player.as_ref().map(|name| format!("{}님의 결과", name));
+                                // 같은 경기의 두 번째 선수가 남았으면 바로 넘기기
+                                let label = if bracket.current_turn() == Some(1) { "다음 선수 ▶" } else { "대진표 보기 ▶" };
+                                next = Some((label, ctx.link().callback(|_| Msg::PlayMatch)));
+                            }
+                            if self.practice {
+                                title = Some(format!("평균 오차 {:.1}", self.breakdown.as_ref().map_or(0.0, |breakdown| breakdown.mean_deviation)));
+                            }
+                            let on_level_select = self.campaign_level.map(|_| ctx.link().callback(|_| Msg::OpenLevelSelect));
+
                             html! {
                                 <ResultScreen
-                                    score={self.score.unwrap_or(0)}
+                                    score={self.score()}
+                                    pattern={self.pattern.clone()}
+                                    time_mode={self.current_time_mode()}
+                                    reveal_guide={matches!(self.game_mode, GameMode::Memory { .. })}
                                     result_path={self.result_path.clone()}
                                     on_retry={retry}
+                                    retryable={self.party.is_none() && self.tournament.is_none()}
                                     remaining_time={self.remaining_time}
+                                    title={title.map(AttrValue::from)}
+                                    next_label={next.as_ref().map(|(label, _)| AttrValue::from(*label))}
+                                    on_next={next.map(|(_, callback)| callback)}
+                                    {on_level_select}
+                                    ghost={self.racing_ghost().cloned()}
+                                    challenge_code={self.challenge_code.clone().map(AttrValue::from)}
+                                    shareable={!self.practice}
+                                    on_back={self.practice.then(|| ctx.link().callback(|_| Msg::BackToStart))}
+                                    new_achievements={self.new_achievements.clone()}
+                                    interrupted={self.interrupted}
+                                    out_of_bounds_time={self.breakdown.as_ref().map_or(0.0, |breakdown| breakdown.out_of_bounds_time)}
+                                    left_play_area={self.left_area}
+                                    assisted={self.breakdown.as_ref().is_some_and(|breakdown| breakdown.assisted)}
                                 />
                             }
                         }
-                        GameState::UnsupportedDevice => {
+                        GameState::RoundClear => {
+                            match &self.survival {
+                                Some(run) => html! { <RoundClear run={run.clone()} score={self.score()} /> },
+                                None => html! {},
+                            }
+                        }
+                        GameState::SurvivalOver => {
+                            match &self.survival {
+                                Some(run) => html! {
+                                    <SurvivalOver
+                                        run={run.clone()}
+                                        last_score={self.score()}
+                                        on_retry={ctx.link().callback(|_| Msg::StartSurvival)}
+                                        on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                    />
+                                },
+                                None => html! {},
+                            }
+                        }
+                        GameState::DuelScreen => {
+                            match &self.duel {
+                                Some(duel) => html! {
+                                    <DuelScreen
+                                        session={duel.clone()}
+                                        pattern={self.pattern.clone()}
+                                        remaining_time={self.remaining_time}
+                                        draw_phase={self.draw_phase}
+                                        show_direction_guide={self.current_difficulty().shows_direction_guide()}
+                                        on_start_draw={ctx.link().callback(Msg::DuelStartDraw)}
+                                        on_stroke_end={ctx.link().callback(Msg::DuelStrokeEnd)}
+                                        on_stroke_cancel={ctx.link().callback(Msg::DuelStrokeCancel)}
+                                        on_leave_area={self.fail_out_of_bounds.then(|| ctx.link().callback(Msg::DuelLeaveArea))}
+                                        paused={self.paused_at.is_some()}
+                                        on_resume={ctx.link().callback(|_| Msg::Resume)}
+                                        assist={self.assist}
+                                    />
+                                },
+                                None => html! {},
+                            }
+                        }
+                        GameState::DuelResult => {
+                            match &self.duel {
+                                Some(duel) => html! {
+                                    <DuelResult
+                                        session={duel.clone()}
+                                        pattern={self.pattern.clone()}
+                                        on_rematch={ctx.link().callback(|_| Msg::StartGame)}
+                                        on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                    />
+                                },
+                                None => html! {},
+                            }
+                        }
+                        GameState::PartySetup => {
                             html! {
-                                <div class="unsupported-device">
-                                    <div>
-                                        <p>{ "이 챌린지는 터치 기반 모바일 디바이스에서만 실행 가능합니다!" }</p>
-                                        <p>{ "모바일 디바이스로 접속해주세요 🥹" }</p>
-                                    </div>
-                                </div>
+                                <PartySetup
+                                    on_start={ctx.link().callback(Msg::StartParty)}
+                                    on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                />
+                            }
+                        }
+                        GameState::PartyHandoff => {
+                            match &self.party {
+                                Some(party) => html! {
+                                    <PartyHandoff
+                                        session={party.clone()}
+                                        on_ready={ctx.link().callback(|_| Msg::StartGame)}
+                                    />
+                                },
+                                None => html! {},
+                            }
+                        }
+                        GameState::PartyStandings => {
+                            match &self.party {
+                                Some(party) => {
+                                    let names: Vec<String> = party.players.iter().map(|player| player.name.clone()).collect();
+                                    html! {
+                                        <PartyStandings
+                                            session={party.clone()}
+                                            on_replay={ctx.link().callback(move |_| Msg::StartParty(names.clone()))}
+                                            on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                        />
+                                    }
+                                }
+                                None => html! {},
+                            }
+                        }
+                        GameState::Stats => {
+                            html! {
+                                <StatsScreen
+                                    record={self.daily_record.clone()}
+                                    today={today()}
+                                    on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                />
+                            }
+                        }
+                        GameState::Trophies => {
+                            html! {
+                                <TrophyScreen
+                                    progress={self.achievements.clone()}
+                                    on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                />
+                            }
+                        }
+                        GameState::TournamentSetup => {
+                            html! {
+                                <PartySetup
+                                    on_start={ctx.link().callback(Msg::StartTournament)}
+                                    on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                    max_players={MAX_ENTRANTS}
+                                    start_label="대진표 만들기"
+                                />
+                            }
+                        }
+                        GameState::Bracket => {
+                            match &self.tournament {
+                                Some(bracket) => html! {
+                                    <BracketScreen
+                                        bracket={bracket.clone()}
+                                        on_play={ctx.link().callback(|_| Msg::PlayMatch)}
+                                        on_new={ctx.link().callback(|_| Msg::NewTournament)}
+                                        on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                    />
+                                },
+                                None => html! {},
                             }
                         }
-                        GameState::LandscapeMode => { 
+                        GameState::TournamentHandoff => {
+                            match &self.tournament {
+                                Some(bracket) => html! {
+                                    <BracketHandoff
+                                        bracket={bracket.clone()}
+                                        on_ready={ctx.link().callback(|_| Msg::StartGame)}
+                                    />
+                                },
+                                None => html! {},
+                            }
+                        }
+                        GameState::LevelSelect => {
+                            html! {
+                                <LevelSelectScreen
+                                    progress={self.campaign_progress.clone()}
+                                    on_select_level={ctx.link().callback(Msg::StartLevel)}
+                                    on_back={ctx.link().callback(|_| Msg::BackToStart)}
+                                />
+                            }
+                        }
+                        GameState::UnsupportedDevice => {
                             html! {
                                 <div class="unsupported-device">
                                     <div>
-                                        <p>{ "세로 모드로 전환해주세요!" }</p>
-                                        <p>{ "화면 방향을 변경한 뒤 게임을 계속해주세요 🥹" }</p>
+                                        <p>{ "이 기기에서는 챌린지를 실행할 수 없어요!" }</p>
+                                        <p>{ "아래 항목을 확인해주세요 🥹" }</p>
                                     </div>
+                                    {
+                                        if let Some(device) = &self.device {
+                                            html! { <CompatibilityReport report={device.clone()} /> }
+                                        } else {
+                                            html! {}
+                                        }
+                                    }
                                 </div>
                             }
                         }
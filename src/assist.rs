@@ -0,0 +1,86 @@
+use crate::func::{distance_to_pattern, nearest_point_on_segment};
+use crate::pattern::{VIEW_BOX_HEIGHT, VIEW_BOX_WIDTH};
+use serde::{Deserialize, Serialize};
+
+// 가이드에 끌어당기는 최대 거리 (viewBox 좌표)
+const SNAP_DISTANCE: f64 = 24.0;
+// 가이드 쪽으로 옮기는 비율 (1.0이면 가이드 위에 완전히 붙음)
+const SNAP_STRENGTH: f64 = 0.7;
+// 방향키 한 번에 움직이는 거리 (Shift를 누르면 `FAST_STEP`)
+pub const CURSOR_STEP: f64 = 4.0;
+pub const FAST_STEP: f64 = 12.0;
+
+/// 보조 모드 설정 (운동 기능에 어려움이 있는 사용자용)
+///
+/// 기기를 바꾸지 않는 한 계속 쓰도록 브라우저에 저장합니다.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AssistSettings {
+    pub enabled: bool,
+    pub time_scale: f64, // 제한 시간 배율
+    pub snap: bool,      // 그린 좌표를 가까운 가이드 쪽으로 끌어당김
+    pub keyboard: bool,  // 방향키로 커서를 움직이고 스페이스/엔터로 펜을 누르고 뗌
+}
+
+impl AssistSettings {
+    pub const TIME_SCALES: [f64; 3] = [2.0, 3.0, 4.0];
+
+    /// 보조 모드가 켜져 있을 때만 늘어난 제한 시간
+    pub fn scale_duration(&self, duration: f64) -> f64 {
+        if self.enabled { duration * self.time_scale } else { duration }
+    }
+
+    /// 가이드 끌어당기기가 켜져 있으면 보정한 좌표
+    pub fn adjust(&self, point: (f64, f64), pattern: &[(f64, f64)]) -> (f64, f64) {
+        if self.enabled && self.snap {
+            snap_to_guide(point, pattern)
+        } else {
+            point
+        }
+    }
+
+    pub fn uses_keyboard(&self) -> bool {
+        self.enabled && self.keyboard
+    }
+}
+
+impl Default for AssistSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            time_scale: Self::TIME_SCALES[0],
+            snap: true,
+            keyboard: false,
+        }
+    }
+}
+
+/// `SNAP_DISTANCE` 안에 있는 좌표를 가장 가까운 패턴 선분 쪽으로 이동
+pub fn snap_to_guide(point: (f64, f64), pattern: &[(f64, f64)]) -> (f64, f64) {
+    let (distance, segment) = distance_to_pattern(point, pattern);
+    if distance > SNAP_DISTANCE || pattern.len() < 2 {
+        return point;
+    }
+
+    let nearest = nearest_point_on_segment(point, pattern[segment], pattern[segment + 1]);
+    (
+        point.0 + (nearest.0 - point.0) * SNAP_STRENGTH,
+        point.1 + (nearest.1 - point.1) * SNAP_STRENGTH,
+    )
+}
+
+/// 방향키 입력으로 커서를 옮긴 위치 (viewBox 안으로 제한), 방향키가 아니면 `None`
+pub fn move_cursor(cursor: (f64, f64), key: &str, fast: bool) -> Option<(f64, f64)> {
+    let step = if fast { FAST_STEP } else { CURSOR_STEP };
+    let (dx, dy) = match key {
+        "ArrowLeft" => (-step, 0.0),
+        "ArrowRight" => (step, 0.0),
+        "ArrowUp" => (0.0, -step),
+        "ArrowDown" => (0.0, step),
+        _ => return None,
+    };
+
+    Some((
+        (cursor.0 + dx).clamp(0.0, VIEW_BOX_WIDTH),
+        (cursor.1 + dy).clamp(0.0, VIEW_BOX_HEIGHT),
+    ))
+}
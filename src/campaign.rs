@@ -0,0 +1,151 @@
+use crate::app::{Difficulty, TimeMode};
+use crate::pattern::Pattern;
+use serde::{Deserialize, Serialize};
+
+/// 캠페인 레벨 (패턴 + 난이도 + 제한 시간 + 목표 점수)
+pub struct Level {
+    pub name: &'static str,
+    pub pattern_seed: Option<u32>, // 없으면 기본 트리, 있으면 이 시드로 만든 고정 모양
+    pub difficulty: Difficulty,
+    pub time_mode: TimeMode,
+    pub target_score: u32,
+}
+
+pub const LEVELS: [Level; 6] = [
+    Level {
+        name: "첫 트리",
+        pattern_seed: None,
+        difficulty: Difficulty::Easy,
+        time_mode: TimeMode::Relaxed,
+        target_score: 60,
+    },
+    Level {
+        name: "익숙해지기",
+        pattern_seed: Some(12),
+        difficulty: Difficulty::Easy,
+        time_mode: TimeMode::Classic,
+        target_score: 70,
+    },
+    Level {
+        name: "정석대로",
+        pattern_seed: Some(27),
+        difficulty: Difficulty::Normal,
+        time_mode: TimeMode::Classic,
+        target_score: 75,
+    },
+    Level {
+        name: "서둘러요",
+        pattern_seed: Some(48),
+        difficulty: Difficulty::Normal,
+        time_mode: TimeMode::Blitz,
+        target_score: 70,
+    },
+    Level {
+        name: "안내 없이",
+        pattern_seed: Some(73),
+        difficulty: Difficulty::Hard,
+        time_mode: TimeMode::Classic,
+        target_score: 80,
+    },
+    Level {
+        name: "트리 마스터",
+        pattern_seed: Some(95),
+        difficulty: Difficulty::Hard,
+        time_mode: TimeMode::Blitz,
+        target_score: 85,
+    },
+];
+
+impl Level {
+    pub fn pattern(&self) -> Pattern {
+        self.pattern_seed.map_or_else(Pattern::tree, Pattern::procedural_tree)
+    }
+}
+
+/// 레벨별 최고 점수 (로컬 저장)
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CampaignProgress {
+    pub best_scores: Vec<Option<u32>>,
+}
+
+impl CampaignProgress {
+    pub fn best_score(&self, level: usize) -> Option<u32> {
+        self.best_scores.get(level).copied().flatten()
+    }
+
+    /// 목표 점수를 달성한 레벨인지 확인
+    pub fn is_cleared(&self, level: usize) -> bool {
+        match (LEVELS.get(level), self.best_score(level)) {
+            (Some(info), Some(score)) => score >= info.target_score,
+            _ => false,
+        }
+    }
+
+    /// 첫 레벨이거나 이전 레벨을 통과하면 잠금 해제
+    pub fn is_unlocked(&self, level: usize) -> bool {
+        level == 0 || self.is_cleared(level - 1)
+    }
+
+    /// 점수를 기록하고 최고 점수가 갱신되었는지 반환
+    pub fn record(&mut self, level: usize, score: u32) -> bool {
+        if self.best_scores.len() <= level {
+            self.best_scores.resize(level + 1, None);
+        }
+
+        let best = &mut self.best_scores[level];
+        if best.is_none_or(|best| score > best) {
+            *best = Some(score);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacent_levels_use_different_patterns() {
+        for pair in LEVELS.windows(2) {
+            assert!(pair[0].pattern() != pair[1].pattern(), "{} / {}", pair[0].name, pair[1].name);
+        }
+    }
+
+    #[test]
+    fn record_keeps_best_score() {
+        let mut progress = CampaignProgress::default();
+
+        assert!(progress.record(2, 60));
+        assert!(!progress.record(2, 55));
+        assert!(!progress.record(2, 60));
+        assert!(progress.record(2, 72));
+        assert_eq!(progress.best_score(2), Some(72));
+        assert_eq!(progress.best_score(0), None);
+        assert_eq!(progress.best_score(LEVELS.len()), None);
+    }
+
+    #[test]
+    fn only_first_level_starts_unlocked() {
+        let progress = CampaignProgress::default();
+
+        assert!(progress.is_unlocked(0));
+        assert!((1..LEVELS.len()).all(|level| !progress.is_unlocked(level)));
+    }
+
+    #[test]
+    fn reaching_target_unlocks_next_level() {
+        let mut progress = CampaignProgress::default();
+        let target = LEVELS[0].target_score;
+
+        progress.record(0, target - 1);
+        assert!(!progress.is_cleared(0));
+        assert!(!progress.is_unlocked(1));
+
+        progress.record(0, target);
+        assert!(progress.is_cleared(0));
+        assert!(progress.is_unlocked(1));
+        assert!(!progress.is_unlocked(2));
+    }
+}
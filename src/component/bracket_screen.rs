@@ -0,0 +1,115 @@
+use crate::tournament::{Bracket, Match};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct BracketScreenProps {
+    pub bracket: Bracket,
+    pub on_play: Callback<MouseEvent>,
+    pub on_new: Callback<MouseEvent>,
+    pub on_back: Callback<MouseEvent>,
+}
+
+/// 토너먼트 대진표 화면
+#[function_component(BracketScreen)]
+pub fn bracket_screen(props: &BracketScreenProps) -> Html {
+    let current = props.bracket.current_match();
+
+    let rounds = props.bracket.rounds.iter().enumerate().map(|(round, matches)| {
+        let games = matches.iter().enumerate().map(|(index, game)| {
+            let is_current = current == Some((round, index));
+            html! {
+                <div class={classes!("bracket-match", is_current.then_some("current"))}>
+                    { player_row(game, 0) }
+                    { player_row(game, 1) }
+                </div>
+            }
+        });
+
+        html! {
+            <div class="bracket-round">
+                <p class="bracket-round-label">{ props.bracket.round_label(round) }</p>
+                { for games }
+            </div>
+        }
+    });
+
+    html! {
+        <div class="screen">
+            <div class="sentence">
+                {
+                    match props.bracket.champion() {
+                        Some(champion) => html! { <h3>{ format!("🏆 {}님 우승!", champion) }</h3> },
+                        None => html! { <h3>{ "토너먼트 대진표" }</h3> },
+                    }
+                }
+            </div>
+            <div class="bracket">
+                { for rounds }
+            </div>
+            <button class="back-link standings-back" onclick={props.on_back.clone()}>{ "처음으로" }</button>
+            {
+                match current {
+                    Some((round, _)) => html! {
+                        <button onclick={props.on_play.clone()} class="start-button">
+                            { format!("{} 경기 시작", props.bracket.round_label(round)) }
+                        </button>
+                    },
+                    None => html! {
+                        <button onclick={props.on_new.clone()} class="start-button">{ "새 토너먼트" }</button>
+                    },
+                }
+            }
+        </div>
+    }
+}
+
+// 경기의 한 자리 (이름과 점수)
+fn player_row(game: &Match, slot: usize) -> Html {
+    let name = match (&game.players[slot], game.winner) {
+        (Some(name), _) => name.clone(),
+        (None, Some(_)) => "부전승".to_string(),
+        (None, None) => "-".to_string(),
+    };
+    let score = game.results[slot]
+        .as_ref()
+        .map(|result| format!("{}점", result.score))
+        .unwrap_or_default();
+
+    html! {
+        <div class={classes!("bracket-player", (game.winner == Some(slot)).then_some("winner"))}>
+            <span>{ name }</span>
+            <span class="bracket-score">{ score }</span>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BracketHandoffProps {
+    pub bracket: Bracket,
+    pub on_ready: Callback<MouseEvent>,
+}
+
+/// 다음 선수에게 기기를 넘기는 화면
+#[function_component(BracketHandoff)]
+pub fn bracket_handoff(props: &BracketHandoffProps) -> Html {
+    let Some((round, index)) = props.bracket.current_match() else {
+        return html! {};
+    };
+    let game = &props.bracket.rounds[round][index];
+    let versus = format!(
+        "{} vs {}",
+        game.players[0].as_deref().unwrap_or_default(),
+        game.players[1].as_deref().unwrap_or_default(),
+    );
+
+    html! {
+        <div class="screen">
+            <div class="round-clear">
+                <p>{ format!("{} · {}", props.bracket.round_label(round), versus) }</p>
+                <h1>{ format!("{}님 차례예요", props.bracket.current_player().unwrap_or_default()) }</h1>
+                <p>{ "휴대폰을 넘겨주세요 📱" }</p>
+            </div>
+            <button onclick={props.on_ready.clone()} class="start-button">{ "준비됐어요" }</button>
+        </div>
+    }
+}
@@ -0,0 +1,33 @@
+use crate::device::DeviceReport;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct CompatibilityReportProps {
+    pub report: DeviceReport,
+}
+
+/// 기기 호환성 항목별 결과 목록
+#[function_component(CompatibilityReport)]
+pub fn compatibility_report(props: &CompatibilityReportProps) -> Html {
+    let checks = props.report.checks().into_iter().map(|check| {
+        let icon = match (check.passed, check.required) {
+            (true, _) => "✅",
+            (false, true) => "❌",
+            (false, false) => "➖",
+        };
+
+        html! {
+            <li class={classes!("compatibility-check", (!check.passed && check.required).then_some("failed"))}>
+                <span class="compatibility-icon">{ icon }</span>
+                <span class="compatibility-label">{ check.label }</span>
+                <span class="compatibility-detail">{ check.detail }</span>
+            </li>
+        }
+    });
+
+    html! {
+        <ul class="compatibility-report">
+            { for checks }
+        </ul>
+    }
+}
@@ -0,0 +1,524 @@
+use crate::assist::{move_cursor, AssistSettings};
+use crate::component::live_stroke::{FrameLoop, LiveOverlay, LiveStroke, LiveStrokeLayer};
+use crate::component::pattern_outline::PatternOutline;
+use crate::func::{deviation_color, distance_to_pattern};
+use crate::palm;
+use crate::pattern::{Pattern, VIEW_BOX_HEIGHT, VIEW_BOX_WIDTH};
+use crate::stroke::{StrokeBuffer, StrokeSample};
+use std::cell::RefCell;
+use web_sys::{AddEventListenerOptions, KeyboardEvent, Node, PointerEvent, SvgElement, TouchEvent};
+use yew::prelude::*;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::js_sys::{Array, Function, Reflect};
+
+// 키보드로 펜을 누른 상태를 나타내는 가상 pointerId (실제 포인터와 겹치지 않음)
+const KEYBOARD_POINTER: i32 = i32::MIN;
+
+#[derive(Properties, PartialEq)]
+pub struct DrawingBoardProps {
+    pub pattern: Pattern,
+    pub stroke: StrokeBuffer,       // 그린 좌표를 쌓는 버퍼
+    pub show_direction_guide: bool, // 시작점과 진행 방향 화살표 표시 여부
+    pub show_guide: bool,           // 트리 가이드 표시 여부 (기억 모드에서는 미리보기 후 숨김)
+    pub accepts_input: bool,        // 미리보기/카운트다운 중에는 입력을 받지 않음
+    pub on_start_draw: Callback<()>,
+    pub on_stroke_end: Callback<()>,
+    #[prop_or_default]
+    pub on_stroke_cancel: Option<Callback<()>>, // 브라우저가 입력을 가로채 선이 끊김 (없으면 on_stroke_end)
+    #[prop_or_default]
+    pub on_leave_area: Option<Callback<()>>, // 있으면 그리는 영역을 벗어나는 즉시 선을 끝냄
+    #[prop_or_default]
+    pub children: Html, // 가이드와 사용자 선 사이에 그릴 요소 (고스트 등)
+    #[prop_or_default]
+    pub feedback: Option<LiveFeedback>, // 연습 모드 실시간 피드백
+    #[prop_or_default]
+    pub paused: bool, // 라운드가 멈추면 손을 떼도 끝내지 않고, 다시 누르면 같은 선을 이어 그림
+    #[prop_or_default]
+    pub assist: AssistSettings, // 보조 모드 (가이드 끌어당기기, 키보드 입력)
+}
+
+/// 그리는 동안 좌표마다 패턴과의 거리를 보여주는 설정
+#[derive(Clone, Copy, PartialEq)]
+pub struct LiveFeedback {
+    pub tolerance: f64, // 이 거리를 벗어나면 선 색이 바뀜
+    pub haptics: bool,  // 허용 거리를 벗어나는 순간 진동
+}
+
+/// 패턴 가이드 위에 한 번에 이어서 선을 그리는 보드
+///
+/// 마우스, 펜, 터치 모두 Pointer Events로 받으며, 처음 이 보드에서 시작된 포인터 하나만
+/// 캡처해서 추적하므로 여러 보드를 동시에 사용할 수 있습니다.
+/// 태블릿에서 손바닥이 닿은 터치나 트리 밖에서 시작한 터치는 무시하며([`palm`]),
+/// 추적 중인 포인터를 뗄 때만 선이 끝납니다.
+/// 선을 그리는 동안에는 문서 전체의 터치 기본 동작(스크롤, 확대, 당겨서 새로고침)을 막습니다.
+/// 보조 모드의 키보드 입력은 방향키로 커서를 옮기고 스페이스/엔터로 펜을 누르고 떼며,
+/// 같은 키를 보내는 스위치 장치로도 조작할 수 있습니다.
+#[function_component(DrawingBoard)]
+pub fn drawing_board(props: &DrawingBoardProps) -> Html {
+    let svg_ref = use_node_ref();
+    let active_pointer = use_mut_ref(|| None::<i32>); // 추적 중인 pointerId
+    let is_finished = use_mut_ref(|| false);        // 한 번 손을 떼면 더 이상 그리지 않음
+    let was_inside = use_mut_ref(|| true);          // 직전 좌표가 허용 거리 안이었는지
+    let on_cancel = use_mut_ref(Callback::<()>::default); // 리스너를 다시 등록하지 않도록 최신 콜백만 교체
+    *on_cancel.borrow_mut() = props.on_stroke_cancel.clone().unwrap_or_else(|| props.on_stroke_end.clone());
+    let start_point = props.pattern.start_point().unwrap_or((VIEW_BOX_WIDTH / 2.0, 0.0));
+    let cursor = use_state(|| start_point); // 키보드 입력 커서 위치
+    let keyboard_pen = use_state_eq(|| false); // 키보드로 펜을 누른 상태 (바뀌면 커서 모양을 다시 그림)
+    let frame = use_mut_ref(|| None::<FrameLoop>); // 선을 이어 그리는 애니메이션 프레임 루프
+    let overlay = use_mut_ref(LiveOverlay::default); // 다음 프레임에 그릴 예측 좌표와 피드백
+
+    // 이벤트마다 새 클로저를 만들지 않고, 보드가 있는 동안 하나의 프레임 루프로 새 좌표만 그림
+    {
+        let svg_ref = svg_ref.clone();
+        let frame = frame.clone();
+        let overlay = overlay.clone();
+
+        use_effect_with(props.stroke.clone(), move |stroke| {
+            if let Some(svg) = svg_ref.cast::<SvgElement>() {
+                let stroke = stroke.clone();
+                let overlay = overlay.clone();
+                let mut renderer = LiveStroke::new(svg);
+                *frame.borrow_mut() = Some(FrameLoop::new(move || renderer.render(&stroke, &overlay.borrow())));
+            }
+
+            move || drop(frame.borrow_mut().take())
+        });
+    }
+
+    // 키보드 입력을 바로 받을 수 있도록 보드에 포커스
+    {
+        let svg_ref = svg_ref.clone();
+
+        use_effect_with(props.assist.uses_keyboard(), move |keyboard| {
+            if let Some(svg) = svg_ref.cast::<SvgElement>().filter(|_| *keyboard) {
+                let _ = svg.focus();
+            }
+        });
+    }
+
+    // 멈출 때와 다시 시작할 때 추적 중인 포인터를 놓아, 새로 누른 포인터로 같은 선을 이어 그림
+    // (멈춘 뒤에 손을 떼면 is_finished가 설정되므로 다시 시작할 때도 초기화)
+    {
+        let active_pointer = active_pointer.clone();
+        let is_finished = is_finished.clone();
+        let keyboard_pen = keyboard_pen.clone();
+
+        use_effect_with(props.paused, move |_| {
+            *active_pointer.borrow_mut() = None;
+            *is_finished.borrow_mut() = false;
+            keyboard_pen.set(false);
+        });
+    }
+
+    // 선을 그리는 동안 브라우저 제스처 차단, 제스처로 터치가 취소되면 끊긴 선으로 보고
+    {
+        let svg_ref = svg_ref.clone();
+        let active_pointer = active_pointer.clone();
+        let is_finished = is_finished.clone();
+        let on_cancel = on_cancel.clone();
+
+        use_effect_with((), move |_| {
+            let guard = TouchGuard::attach(move |event: &TouchEvent| {
+                if active_pointer.borrow().is_none() || *is_finished.borrow() {
+                    return;
+                }
+                if event.type_() != "touchcancel" {
+                    event.prevent_default();
+                    return;
+                }
+                // 이 보드에서 시작된 터치가 취소된 경우만 처리
+                let Some(svg) = svg_ref.cast::<Node>() else { return };
+                let touches = event.changed_touches();
+                let ours = (0..touches.length())
+                    .filter_map(|index| touches.get(index))
+                    .filter_map(|touch| touch.target())
+                    .any(|target| target.dyn_ref::<Node>().is_some_and(|node| svg.contains(Some(node))));
+                if ours && !is_finished.replace(true) {
+                    active_pointer.borrow_mut().take();
+                    on_cancel.borrow().emit(());
+                }
+            });
+
+            move || drop(guard)
+        });
+    }
+
+    // 포인터 누름 처리 (첫 입력에서 타이머 시작)
+    let on_pointer_down = {
+        let svg_ref = svg_ref.clone();
+        let active_pointer = active_pointer.clone();
+        let is_finished = is_finished.clone();
+        let stroke = props.stroke.clone();
+        let on_start_draw = props.on_start_draw.clone();
+        let accepts_input = props.accepts_input;
+        let pattern = props.pattern.clone();
+        let assist = props.assist;
+        let frame = frame.clone();
+
+        Callback::from(move |event: PointerEvent| {
+            // 마우스는 왼쪽 버튼만 사용
+            if !accepts_input || event.button() != 0 || *is_finished.borrow() || active_pointer.borrow().is_some() {
+                return;
+            }
+            if let Some(svg) = svg_ref.cast::<SvgElement>() {
+                let point = to_view_box(&svg, &event);
+                if !palm::accepts_start(&event, point, &pattern.points) {
+                    return;
+                }
+                event.prevent_default();
+                // 보드 밖으로 나가도 이 보드가 계속 이벤트를 받도록 캡처
+                let _ = svg.set_pointer_capture(event.pointer_id());
+                *active_pointer.borrow_mut() = Some(event.pointer_id());
+                let point = assist.adjust(point, &pattern.points);
+                stroke.push(StrokeSample::from_event(&event, point));
+                request_frame(&frame);
+                on_start_draw.emit(());
+            }
+        })
+    };
+
+    // 포인터 이동 처리
+    let on_update_draw = {
+        let svg_ref = svg_ref.clone();
+        let active_pointer = active_pointer.clone();
+        let was_inside = was_inside.clone();
+        let is_finished = is_finished.clone();
+        let stroke = props.stroke.clone();
+        let pattern = props.pattern.clone();
+        let feedback = props.feedback;
+        let on_leave_area = props.on_leave_area.clone();
+        let assist = props.assist;
+        let frame = frame.clone();
+        let overlay = overlay.clone();
+
+        Callback::from(move |event: PointerEvent| {
+            if *active_pointer.borrow() != Some(event.pointer_id()) || *is_finished.borrow() {
+                return;
+            }
+            if let Some(svg) = svg_ref.cast::<SvgElement>() {
+                // 프레임 사이에 합쳐진 중간 좌표까지 모두 기록 (지원하지 않으면 현재 이벤트만)
+                let events = event_list(&event, "getCoalescedEvents")
+                    .filter(|events| !events.is_empty())
+                    .unwrap_or_else(|| vec![event.clone()]);
+
+                let mut nearest = None;
+                for event in &events {
+                    let sample = StrokeSample::from_event(event, assist.adjust(to_view_box(&svg, event), &pattern.points));
+                    let point = sample.point();
+                    stroke.push(sample);
+
+                    // 영역 이탈 실패 규칙: 벗어난 좌표까지 기록한 뒤 바로 종료
+                    if let Some(on_leave_area) = on_leave_area.as_ref().filter(|_| sample.out_of_bounds) {
+                        if !is_finished.replace(true) {
+                            on_leave_area.emit(());
+                        }
+                        break;
+                    }
+
+                    // 연습 모드: 좌표마다 가장 가까운 선분과의 거리로 채점
+                    nearest = feedback.map(|feedback| {
+                        let (deviation, segment) = distance_to_pattern(point, &pattern.points);
+                        let inside = deviation <= feedback.tolerance;
+                        let left_tolerance = was_inside.replace(inside) && !inside;
+                        if feedback.haptics && left_tolerance {
+                            if let Some(window) = web_sys::window() {
+                                window.navigator().vibrate_with_duration(20);
+                            }
+                        }
+                        (deviation_color(deviation, feedback.tolerance), pattern.points[segment], pattern.points[segment + 1])
+                    });
+                }
+
+                // 예측 좌표는 화면에만 덧붙여 지연을 줄임 (기록과 채점에는 사용하지 않음)
+                let mut overlay = overlay.borrow_mut();
+                overlay.predicted = match event_list(&event, "getPredictedEvents") {
+                    Some(predicted) if !*is_finished.borrow() => predicted.iter().map(|event| to_view_box(&svg, event)).collect(),
+                    _ => vec![],
+                };
+                if nearest.is_some() {
+                    overlay.feedback = nearest;
+                }
+
+                // 렌더링은 브라우저 애니메이션 프레임에 한 번만
+                request_frame(&frame);
+            }
+        })
+    };
+
+    // 손을 떼면 예측 좌표로 덧붙였던 꼬리를 지움
+    let clear_prediction = {
+        let frame = frame.clone();
+        let overlay = overlay.clone();
+
+        move || {
+            overlay.borrow_mut().predicted.clear();
+            request_frame(&frame);
+        }
+    };
+
+    // 포인터 뗌 처리 (추적 중인 포인터일 때만)
+    let on_pointer_up = {
+        let active_pointer = active_pointer.clone();
+        let is_finished = is_finished.clone();
+        let on_stroke_end = props.on_stroke_end.clone();
+        let clear_prediction = clear_prediction.clone();
+
+        Callback::from(move |event: PointerEvent| {
+            if *active_pointer.borrow() == Some(event.pointer_id()) && !is_finished.replace(true) {
+                clear_prediction();
+                on_stroke_end.emit(());
+            }
+        })
+    };
+
+    // 포인터 취소 처리 (브라우저가 스크롤이나 제스처로 입력을 가져감)
+    let on_pointer_cancel = {
+        let active_pointer = active_pointer.clone();
+        let is_finished = is_finished.clone();
+        let on_cancel = on_cancel.clone();
+
+        Callback::from(move |event: PointerEvent| {
+            if *active_pointer.borrow() == Some(event.pointer_id()) && !is_finished.replace(true) {
+                clear_prediction();
+                on_cancel.borrow().emit(());
+            }
+        })
+    };
+
+    // 보조 모드 키보드 입력 (방향키 이동, 스페이스/엔터로 펜 누름과 뗌)
+    let on_key_down = {
+        let active_pointer = active_pointer.clone();
+        let is_finished = is_finished.clone();
+        let cursor = cursor.clone();
+        let keyboard_pen = keyboard_pen.clone();
+        let stroke = props.stroke.clone();
+        let pattern = props.pattern.clone();
+        let assist = props.assist;
+        let accepts_input = props.accepts_input;
+        let on_start_draw = props.on_start_draw.clone();
+        let on_stroke_end = props.on_stroke_end.clone();
+        let frame = frame.clone();
+
+        Callback::from(move |event: KeyboardEvent| {
+            if !assist.uses_keyboard() || !accepts_input || *is_finished.borrow() {
+                return;
+            }
+            let pen_down = *active_pointer.borrow() == Some(KEYBOARD_POINTER);
+
+            match event.key().as_str() {
+                " " | "Enter" => {
+                    event.prevent_default();
+                    if pen_down {
+                        keyboard_pen.set(false);
+                        if !is_finished.replace(true) {
+                            on_stroke_end.emit(());
+                        }
+                    } else if active_pointer.borrow().is_none() {
+                        *active_pointer.borrow_mut() = Some(KEYBOARD_POINTER);
+                        keyboard_pen.set(true);
+                        let point = assist.adjust(*cursor, &pattern.points);
+                        stroke.push(StrokeSample::from_key(point, event.time_stamp()));
+                        request_frame(&frame);
+                        on_start_draw.emit(());
+                    }
+                }
+                key => {
+                    let Some(moved) = move_cursor(*cursor, key, event.shift_key()) else { return };
+                    event.prevent_default(); // 방향키로 페이지가 스크롤되지 않도록
+                    cursor.set(moved);
+                    if pen_down {
+                        let point = assist.adjust(moved, &pattern.points);
+                        stroke.push(StrokeSample::from_key(point, event.time_stamp()));
+                        request_frame(&frame);
+                    }
+                }
+            }
+        })
+    };
+
+    let keyboard = props.assist.uses_keyboard();
+
+    html! {
+        <svg class="tree-pattern drawing-board"
+             viewBox="0 0 256 291"
+             preserveAspectRatio="xMidYMin"
+             fill="none"
+             xmlns="http://www.w3.org/2000/svg"
+             ref={svg_ref}
+             onpointerdown={on_pointer_down}
+             onpointermove={on_update_draw}
+             onpointerup={on_pointer_up}
+             onpointercancel={on_pointer_cancel}
+             onkeydown={on_key_down}
+             tabindex={keyboard.then_some("0")}
+             aria-label={keyboard.then_some("트리 그리기 보드: 방향키로 움직이고 스페이스나 엔터로 펜을 누르거나 떼세요")}
+             >
+            <g class={if props.show_guide { "guide" } else { "guide guide-hidden" }}>
+                <PatternOutline outline={props.pattern.outline.clone()} />
+
+                { if props.show_direction_guide { direction_guide(&props.pattern) } else { html! {} } }
+            </g>
+
+            { props.children.clone() }
+
+            {
+                if props.feedback.is_some() {
+                    html! { <line class="nearest-segment" /> }
+                } else {
+                    html! {}
+                }
+            }
+
+            <LiveStrokeLayer />
+
+            {
+                if keyboard {
+                    html! {
+                        <circle
+                            class={classes!("keyboard-cursor", keyboard_pen.then_some("pen-down"))}
+                            cx={cursor.0.to_string()}
+                            cy={cursor.1.to_string()}
+                            r="6"
+                        />
+                    }
+                } else {
+                    html! {}
+                }
+            }
+
+        </svg>
+    }
+}
+
+type TouchListener = Closure<dyn FnMut(TouchEvent)>;
+
+/// 문서에 등록한 non-passive 터치 리스너 (drop 시 해제)
+///
+/// yew가 등록하는 터치 리스너는 passive라서 `preventDefault`가 무시되므로 직접 등록합니다.
+struct TouchGuard {
+    listener: TouchListener,
+}
+
+impl TouchGuard {
+    const EVENTS: [&'static str; 3] = ["touchstart", "touchmove", "touchcancel"];
+
+    fn attach(handler: impl Fn(&TouchEvent) + 'static) -> Option<Self> {
+        let document = web_sys::window()?.document()?;
+        let listener = Closure::wrap(Box::new(move |event: TouchEvent| handler(&event)) as Box<dyn FnMut(TouchEvent)>);
+
+        let options = AddEventListenerOptions::new();
+        options.set_passive(false);
+        for name in Self::EVENTS {
+            let _ = document.add_event_listener_with_callback_and_add_event_listener_options(
+                name,
+                listener.as_ref().unchecked_ref(),
+                &options,
+            );
+        }
+        Some(Self { listener })
+    }
+}
+
+impl Drop for TouchGuard {
+    fn drop(&mut self) {
+        if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+            for name in Self::EVENTS {
+                let _ = document.remove_event_listener_with_callback(name, self.listener.as_ref().unchecked_ref());
+            }
+        }
+    }
+}
+
+/// `getCoalescedEvents`, `getPredictedEvents` 결과 (브라우저가 지원하지 않으면 `None`)
+fn event_list(event: &PointerEvent, method: &str) -> Option<Vec<PointerEvent>> {
+    let function: Function = Reflect::get(event, &JsValue::from_str(method)).ok()?.dyn_into().ok()?;
+    let list: Array = function.call0(event).ok()?.dyn_into().ok()?;
+    Some(list.iter().filter_map(|item| item.dyn_into().ok()).collect())
+}
+
+/// 다음 애니메이션 프레임에 새 좌표 그리기 예약
+fn request_frame(frame: &RefCell<Option<FrameLoop>>) {
+    if let Some(frame) = frame.borrow().as_ref() {
+        frame.request();
+    }
+}
+
+/// 화면 좌표를 패턴 좌표계(viewBox)로 변환
+pub fn to_view_box(svg: &SvgElement, event: &PointerEvent) -> (f64, f64) {
+    let bounding_box = svg.get_bounding_client_rect();
+    let x = (client_coordinate(event, "clientX", event.client_x()) - bounding_box.x())
+        * view_box_scale(VIEW_BOX_WIDTH, bounding_box.width());
+    let y = (client_coordinate(event, "clientY", event.client_y()) - bounding_box.y())
+        * view_box_scale(VIEW_BOX_HEIGHT, bounding_box.height());
+    (x, y)
+}
+
+/// 화면 픽셀 하나가 viewBox 좌표로 몇인지 (가로, 세로 각각)
+///
+/// 보드가 화면 크기에 맞춰 늘거나 줄어도 좌표는 항상 256×291 패턴 좌표계로 맞춰지므로,
+/// 모든 모드에서 채점 허용 거리가 화면 크기와 관계없이 같은 의미를 가집니다.
+/// 아직 그려지지 않아 크기가 0이면 화면 픽셀을 그대로 사용합니다.
+fn view_box_scale(view_box_size: f64, rendered_size: f64) -> f64 {
+    if rendered_size > 0.0 {
+        view_box_size / rendered_size
+    } else {
+        1.0
+    }
+}
+
+/// 소수점 이하까지 담긴 화면 좌표 (`client_x()`는 정수로 잘려서 직접 읽음)
+fn client_coordinate(event: &PointerEvent, name: &str, fallback: i32) -> f64 {
+    Reflect::get(event, &JsValue::from_str(name))
+        .ok()
+        .and_then(|value| value.as_f64())
+        .unwrap_or(fallback as f64)
+}
+
+// 시작점 표시와 진행 방향 화살표
+fn direction_guide(pattern: &Pattern) -> Html {
+    let arrows = pattern.direction_arrows(6).into_iter().map(|(x, y, angle)| {
+        html! {
+            <polygon
+                class="direction-arrow"
+                points="-5,-4 5,0 -5,4"
+                transform={format!("translate({},{}) rotate({})", x, y, angle)}
+            />
+        }
+    });
+
+    html! {
+        <g class="direction-guide">
+            { for arrows }
+            {
+                if let Some((x, y)) = pattern.start_point() {
+                    html! {
+                        <>
+                            <circle class="start-marker-pulse" cx={x.to_string()} cy={y.to_string()} r="6" />
+                            <circle class="start-marker" cx={x.to_string()} cy={y.to_string()} r="6" />
+                        </>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_maps_rendered_size_to_view_box() {
+        assert_eq!(view_box_scale(VIEW_BOX_WIDTH, 512.0), 0.5);
+        assert_eq!(view_box_scale(VIEW_BOX_HEIGHT, 145.5), 2.0);
+        assert_eq!(view_box_scale(VIEW_BOX_WIDTH, VIEW_BOX_WIDTH), 1.0);
+    }
+
+    #[test]
+    fn scale_falls_back_before_layout() {
+        assert_eq!(view_box_scale(VIEW_BOX_WIDTH, 0.0), 1.0);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::app::DrawPhase;
+use crate::assist::AssistSettings;
+use crate::component::drawing_board::DrawingBoard;
+use crate::component::drawing_screen::pause_overlay;
+use crate::component::pattern_outline::PatternOutline;
+use crate::component::stroke_polyline::PressureStroke;
+use crate::duel::{DuelSession, PLAYER_NAMES};
+use crate::func::format_time;
+use crate::pattern::Pattern;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct DuelScreenProps {
+    pub session: DuelSession,
+    pub pattern: Pattern,
+    pub remaining_time: f64,
+    pub draw_phase: DrawPhase,
+    pub show_direction_guide: bool,
+    pub on_start_draw: Callback<usize>,
+    pub on_stroke_end: Callback<usize>,
+    pub on_stroke_cancel: Callback<usize>, // 브라우저 제스처로 선이 끊김
+    #[prop_or_default]
+    pub on_leave_area: Option<Callback<usize>>, // 영역 이탈 실패 규칙
+    #[prop_or_default]
+    pub paused: bool, // 화면 방향이 바뀌어 라운드가 멈춤
+    pub on_resume: Callback<MouseEvent>,
+    #[prop_or_default]
+    pub assist: AssistSettings, // 두 보드 모두 같은 보조 모드 사용
+}
+
+/// 두 개의 트리를 나란히 놓고 동시에 그리는 듀얼 화면
+#[function_component(DuelScreen)]
+pub fn duel_screen(props: &DuelScreenProps) -> Html {
+    let boards = (0..2).map(|player| {
+        html! {
+            <div class="duel-board">
+                <p class="duel-player">{ PLAYER_NAMES[player] }</p>
+                <DrawingBoard
+                    pattern={props.pattern.clone()}
+                    stroke={props.session.strokes[player].clone()}
+                    show_direction_guide={props.show_direction_guide}
+                    show_guide=true
+                    accepts_input={props.draw_phase.accepts_input() && !props.session.finished[player] && !props.paused}
+                    paused={props.paused}
+                    on_start_draw={props.on_start_draw.reform(move |_| player)}
+                    on_stroke_end={props.on_stroke_end.reform(move |_| player)}
+                    on_stroke_cancel={props.on_stroke_cancel.reform(move |_| player)}
+                    on_leave_area={props.on_leave_area.as_ref().map(|callback| callback.reform(move |_| player))}
+                    assist={props.assist}
+                />
+            </div>
+        }
+    });
+
+    html! {
+        <div class="screen drawing-screen">
+            <div class="sentence">
+                <h3>{ "동시에 트리를 그려 승부하세요!" }</h3>
+            </div>
+            <div class="duel-container">
+                { for boards }
+            </div>
+            <div class="duel-timer">
+                { format_time(props.remaining_time) }
+            </div>
+            {
+                match props.draw_phase {
+                    DrawPhase::Countdown(count) => html! {
+                        <div class="duel-countdown" key={count}>{ count }</div>
+                    },
+                    _ => html! {},
+                }
+            }
+            {
+                if props.paused {
+                    pause_overlay(&props.on_resume)
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DuelResultProps {
+    pub session: DuelSession,
+    pub pattern: Pattern,
+    pub on_rematch: Callback<MouseEvent>,
+    pub on_back: Callback<MouseEvent>,
+}
+
+/// 듀얼 결과 화면 (두 사람의 선과 점수, 승자 표시)
+#[function_component(DuelResult)]
+pub fn duel_result(props: &DuelResultProps) -> Html {
+    let winner = props.session.winner();
+
+    let columns = (0..2).map(|player| {
+        let result = props.session.results[player].clone().unwrap_or_default();
+        let is_winner = winner == Some(player);
+
+        html! {
+            <div class={classes!("duel-board", is_winner.then_some("winner"))}>
+                <p class="duel-player">{ if is_winner { format!("🏆 {}", PLAYER_NAMES[player]) } else { PLAYER_NAMES[player].to_string() } }</p>
+                <svg class="tree-pattern" viewBox="0 0 256 291" preserveAspectRatio="xMidYMin" fill="none" xmlns="http://www.w3.org/2000/svg">
+                    <PatternOutline outline={props.pattern.outline.clone()} />
+                    <PressureStroke samples={props.session.paths[player].clone()} />
+                </svg>
+                <p class="duel-score">{ format!("{}점", result.score) }</p>
+                <p class="duel-time">{ format_time(result.time_used) }</p>
+            </div>
+        }
+    });
+
+    html! {
+        <div class="screen">
+            <div class="sentence">
+                <h3>
+                    {
+                        match winner {
+                            Some(player) => format!("{} 승리!", PLAYER_NAMES[player]),
+                            None => "무승부!".to_string(),
+                        }
+                    }
+                </h3>
+            </div>
+            <div class="duel-container">
+                { for columns }
+            </div>
+            <button class="back-link standings-back" onclick={props.on_back.clone()}>{ "처음으로" }</button>
+            <button onclick={props.on_rematch.clone()} class="start-button">{ "다시 대결하기" }</button>
+        </div>
+    }
+}
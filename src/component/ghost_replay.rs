@@ -0,0 +1,54 @@
+use crate::component::stroke_polyline::StrokePolyline;
+use crate::func::{now, path_to_points};
+use crate::ghost::GhostStroke;
+use gloo::timers::callback::Interval;
+use yew::prelude::*;
+
+const FRAME_INTERVAL: u32 = 16;
+
+#[derive(Properties, PartialEq)]
+pub struct GhostReplayProps {
+    pub ghost: GhostStroke,
+    pub running: bool, // 내가 그리기 시작하면 고스트도 함께 출발
+    #[prop_or_default]
+    pub paused: bool,  // 라운드가 멈춘 동안 제자리에서 대기
+}
+
+/// 친구가 그린 선을 같은 속도로 다시 그려주는 반투명 고스트
+#[function_component(GhostReplay)]
+pub fn ghost_replay(props: &GhostReplayProps) -> Html {
+    let elapsed = use_state(|| 0.0);
+
+    {
+        let elapsed = elapsed.clone();
+        let resume_from = *elapsed; // 멈췄다가 다시 출발할 때 이어서 재생
+
+        use_effect_with((props.running, props.paused), move |&(running, paused)| {
+            if !running {
+                elapsed.set(0.0);
+            }
+            let interval = (running && !paused).then(|| {
+                let start_time = now() - resume_from;
+                Interval::new(FRAME_INTERVAL, move || elapsed.set(now() - start_time))
+            });
+
+            move || drop(interval)
+        });
+    }
+
+    let traced = props.ghost.path_until(*elapsed);
+    let head = traced.last().copied();
+
+    html! {
+        <g class="ghost">
+            <StrokePolyline class="ghost-stroke" points={path_to_points(&traced)} />
+            {
+                if let Some((x, y)) = head {
+                    html! { <circle class="ghost-head" cx={x.to_string()} cy={y.to_string()} r="6" /> }
+                } else {
+                    html! {}
+                }
+            }
+        </g>
+    }
+}
@@ -0,0 +1,57 @@
+use crate::campaign::{CampaignProgress, LEVELS};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct LevelSelectScreenProps {
+    pub progress: CampaignProgress,
+    pub on_select_level: Callback<usize>,
+    pub on_back: Callback<MouseEvent>,
+}
+
+#[function_component(LevelSelectScreen)]
+pub fn level_select_screen(props: &LevelSelectScreenProps) -> Html {
+    let levels = LEVELS.iter().enumerate().map(|(index, level)| {
+        let unlocked = props.progress.is_unlocked(index);
+        let cleared = props.progress.is_cleared(index);
+        let class = classes!(
+            "level-button",
+            cleared.then_some("cleared"),
+            (!unlocked).then_some("locked")
+        );
+        let best_score = props
+            .progress
+            .best_score(index)
+            .map(|score| format!("최고 {}점", score))
+            .unwrap_or_else(|| "기록 없음".to_string());
+
+        html! {
+            <button
+                {class}
+                disabled={!unlocked}
+                onclick={props.on_select_level.reform(move |_| index)}
+                >
+                <span class="level-number">{ format!("{}", index + 1) }</span>
+                <span class="level-name">
+                    { if unlocked { level.name } else { "🔒" } }
+                </span>
+                <span class="level-info">
+                    { format!("{} · {} · 목표 {}점", level.difficulty.label(), level.time_mode.label(), level.target_score) }
+                    <br />
+                    { best_score }
+                </span>
+            </button>
+        }
+    });
+
+    html! {
+        <div class="screen">
+            <div class="sentence">
+                <h3>{ "레벨을 선택하세요" }</h3>
+            </div>
+            <div class="level-list">
+                { for levels }
+            </div>
+            <button onclick={props.on_back.clone()} class="start-button">{ "처음으로" }</button>
+        </div>
+    }
+}
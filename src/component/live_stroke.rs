@@ -0,0 +1,192 @@
+use crate::component::stroke_polyline::{STROKE_COLOR, STROKE_WIDTH};
+use crate::func::path_to_points;
+use crate::stroke::StrokeBuffer;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, SvgElement};
+use yew::prelude::*;
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+// 조각 하나에 담는 최대 좌표 수 (한 프레임에 다시 쓰는 points 문자열 길이 상한)
+const CHUNK_SIZE: usize = 64;
+
+/// 그리는 중인 선을 담는 레이어 (조각은 [`LiveStroke`]가 직접 추가)
+#[function_component(LiveStrokeLayer)]
+pub fn live_stroke_layer() -> Html {
+    html! {
+        <g
+            class="live-stroke"
+            stroke={STROKE_COLOR}
+            stroke-width={STROKE_WIDTH.to_string()}
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            fill="none"
+            >
+            <polyline class="predicted-stroke" />
+        </g>
+    }
+}
+
+/// 연습 모드 선 색과 가장 가까운 패턴 선분 (시작점, 끝점)
+pub type SegmentFeedback = (&'static str, (f64, f64), (f64, f64));
+
+/// 다음 프레임에 선과 함께 그릴 화면 전용 정보
+#[derive(Default)]
+pub struct LiveOverlay {
+    pub predicted: Vec<(f64, f64)>, // 예측 좌표 (기록과 채점에는 사용하지 않음)
+    pub feedback: Option<SegmentFeedback>,
+}
+
+/// 그리는 중인 선을 조각(polyline)으로 나눠 이어 붙이는 렌더러
+///
+/// 프레임마다 지난 프레임 이후 추가된 좌표만 마지막 조각에 덧붙이고, 조각이 가득 차면
+/// 새 조각을 시작하므로 선이 길어져도 한 프레임의 작업량이 일정합니다.
+pub struct LiveStroke {
+    svg: SvgElement,
+    layer: Option<Element>,     // <g class="live-stroke"> (처음 그릴 때 찾음)
+    chunks: Vec<Element>,
+    chunk_points: String,       // 마지막 조각의 points 속성
+    chunk_len: usize,
+    last_point: Option<(f64, f64)>,
+    drawn: usize,               // 이미 그린 샘플 개수
+}
+
+impl LiveStroke {
+    pub fn new(svg: SvgElement) -> Self {
+        Self {
+            svg,
+            layer: None,
+            chunks: vec![],
+            chunk_points: String::new(),
+            chunk_len: 0,
+            last_point: None,
+            drawn: 0,
+        }
+    }
+
+    /// 버퍼에 새로 쌓인 좌표와 화면 전용 정보를 그림
+    pub fn render(&mut self, stroke: &StrokeBuffer, overlay: &LiveOverlay) {
+        if self.layer.is_none() {
+            self.layer = self.svg.query_selector("g.live-stroke").ok().flatten();
+        }
+
+        // 새 라운드로 버퍼가 비워졌으면 처음부터 다시 그림
+        if stroke.is_empty() && self.drawn > 0 {
+            self.clear();
+        }
+
+        let new_points = stroke.points_from(self.drawn);
+        self.drawn += new_points.len();
+        for point in new_points {
+            self.append(point);
+        }
+        if let Some(chunk) = self.chunks.last() {
+            let _ = chunk.set_attribute("points", &self.chunk_points);
+        }
+
+        if let Some(predicted) = self.layer.as_ref().and_then(|layer| layer.query_selector("polyline.predicted-stroke").ok().flatten()) {
+            let tail: Vec<_> = self.last_point.iter().chain(overlay.predicted.iter()).copied().collect();
+            let points = if overlay.predicted.is_empty() { String::new() } else { path_to_points(&tail) };
+            let _ = predicted.set_attribute("points", &points);
+        }
+
+        if let Some((color, start, end)) = overlay.feedback {
+            if let Some(layer) = &self.layer {
+                let _ = layer.set_attribute("stroke", color);
+            }
+            if let Some(line) = self.svg.query_selector("line.nearest-segment").ok().flatten() {
+                for (name, value) in [("x1", start.0), ("y1", start.1), ("x2", end.0), ("y2", end.1)] {
+                    let _ = line.set_attribute(name, &value.to_string());
+                }
+            }
+        }
+    }
+
+    fn append(&mut self, (x, y): (f64, f64)) {
+        if self.chunks.is_empty() || self.chunk_len >= CHUNK_SIZE {
+            // 지난 조각을 마무리하고, 끊어지지 않도록 마지막 좌표에서 새 조각 시작
+            if let Some(chunk) = self.chunks.last() {
+                let _ = chunk.set_attribute("points", &self.chunk_points);
+            }
+            let Some(chunk) = self.create_chunk() else { return };
+            self.chunks.push(chunk);
+            self.chunk_points.clear();
+            self.chunk_len = 0;
+            if let Some((last_x, last_y)) = self.last_point {
+                self.chunk_points.push_str(&format!("{},{}", last_x, last_y));
+                self.chunk_len = 1;
+            }
+        }
+
+        if !self.chunk_points.is_empty() {
+            self.chunk_points.push(' ');
+        }
+        self.chunk_points.push_str(&format!("{},{}", x, y));
+        self.chunk_len += 1;
+        self.last_point = Some((x, y));
+    }
+
+    fn create_chunk(&self) -> Option<Element> {
+        let layer = self.layer.as_ref()?;
+        let chunk = layer.owner_document()?.create_element_ns(Some(SVG_NAMESPACE), "polyline").ok()?;
+        let _ = chunk.set_attribute("class", "stroke-chunk");
+        layer.append_child(&chunk).ok()?;
+        Some(chunk)
+    }
+
+    fn clear(&mut self) {
+        for chunk in self.chunks.drain(..) {
+            chunk.remove();
+        }
+        self.chunk_points.clear();
+        self.chunk_len = 0;
+        self.last_point = None;
+        self.drawn = 0;
+    }
+}
+
+/// 하나의 클로저를 재사용하는 requestAnimationFrame 루프
+///
+/// 필요할 때만 다음 프레임을 예약하며, 한 프레임 안에 여러 번 요청해도 한 번만 그립니다.
+/// drop 되면 예약된 프레임을 취소합니다.
+pub struct FrameLoop {
+    callback: Closure<dyn FnMut()>,
+    pending: Rc<Cell<Option<i32>>>, // 예약된 프레임 핸들
+}
+
+impl FrameLoop {
+    pub fn new(mut draw: impl FnMut() + 'static) -> Self {
+        let pending = Rc::new(Cell::new(None));
+        let callback = {
+            let pending = pending.clone();
+            Closure::wrap(Box::new(move || {
+                pending.set(None);
+                draw();
+            }) as Box<dyn FnMut()>)
+        };
+
+        Self { callback, pending }
+    }
+
+    /// 다음 프레임에 그리기 예약 (이미 예약되어 있으면 무시)
+    pub fn request(&self) {
+        if self.pending.get().is_some() {
+            return;
+        }
+        if let Some(window) = web_sys::window() {
+            if let Ok(handle) = window.request_animation_frame(self.callback.as_ref().unchecked_ref()) {
+                self.pending.set(Some(handle));
+            }
+        }
+    }
+}
+
+impl Drop for FrameLoop {
+    fn drop(&mut self) {
+        if let (Some(handle), Some(window)) = (self.pending.take(), web_sys::window()) {
+            let _ = window.cancel_animation_frame(handle);
+        }
+    }
+}
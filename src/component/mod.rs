@@ -1,3 +1,18 @@
+pub mod bracket_screen;
+pub mod compatibility_report;
+pub mod drawing_board;
 pub mod drawing_screen;
+pub mod duel_screen;
+pub mod ghost_replay;
+pub mod level_select_screen;
+pub mod live_stroke;
+pub mod party_screen;
+pub mod pattern_outline;
 pub mod result_screen;
 pub mod start_screen;
+pub mod stats_screen;
+pub mod stroke_polyline;
+pub mod survival_screen;
+pub mod tree_decorations;
+pub mod trophy_screen;
+pub mod tutorial_demo;
@@ -0,0 +1,178 @@
+use crate::func::format_time;
+use crate::party::{PartySession, MAX_PLAYERS, MIN_PLAYERS};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct PartySetupProps {
+    pub on_start: Callback<Vec<String>>,
+    pub on_back: Callback<MouseEvent>,
+    #[prop_or(MAX_PLAYERS)]
+    pub max_players: usize,
+    #[prop_or(AttrValue::Static("파티 시작"))]
+    pub start_label: AttrValue,
+}
+
+/// 참가자 이름 입력 화면 (파티 모드, 토너먼트 공용)
+#[function_component(PartySetup)]
+pub fn party_setup(props: &PartySetupProps) -> Html {
+    let names = use_state(|| vec![String::new(); MIN_PLAYERS]);
+
+    let inputs = names.iter().enumerate().map(|(index, name)| {
+        let on_input = {
+            let names = names.clone();
+            Callback::from(move |event: InputEvent| {
+                let input: HtmlInputElement = event.target_unchecked_into();
+                let mut updated = (*names).clone();
+                updated[index] = input.value();
+                names.set(updated);
+            })
+        };
+        let on_remove = {
+            let names = names.clone();
+            Callback::from(move |_: MouseEvent| {
+                let mut updated = (*names).clone();
+                updated.remove(index);
+                names.set(updated);
+            })
+        };
+
+        html! {
+            <div class="player-input">
+                <input
+                    type="text"
+                    maxlength="10"
+                    placeholder={default_name(index)}
+                    value={name.clone()}
+                    oninput={on_input}
+                />
+                {
+                    if names.len() > MIN_PLAYERS {
+                        html! { <button class="back-link" onclick={on_remove}>{ "✕" }</button> }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    });
+
+    let on_add = {
+        let names = names.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut updated = (*names).clone();
+            updated.push(String::new());
+            names.set(updated);
+        })
+    };
+
+    let on_start = {
+        let names = names.clone();
+        let on_start = props.on_start.clone();
+        Callback::from(move |_: MouseEvent| {
+            // 이름을 비워두면 "플레이어 N"으로 표시
+            let players = names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    let name = name.trim();
+                    if name.is_empty() { default_name(index) } else { name.to_string() }
+                })
+                .collect();
+            on_start.emit(players);
+        })
+    };
+
+    html! {
+        <div class="screen">
+            <div class="sentence">
+                <h3>{ "참가자 이름을 입력하세요" }</h3>
+            </div>
+            <div class="player-list">
+                { for inputs }
+                {
+                    if names.len() < props.max_players {
+                        html! { <button class="back-link" onclick={on_add}>{ "+ 참가자 추가" }</button> }
+                    } else {
+                        html! {}
+                    }
+                }
+                <button class="back-link" onclick={props.on_back.clone()}>{ "처음으로" }</button>
+            </div>
+            <button onclick={on_start} class="start-button">{ props.start_label.clone() }</button>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PartyHandoffProps {
+    pub session: PartySession,
+    pub on_ready: Callback<MouseEvent>,
+}
+
+/// 다음 플레이어에게 기기를 넘기는 화면
+#[function_component(PartyHandoff)]
+pub fn party_handoff(props: &PartyHandoffProps) -> Html {
+    let name = props
+        .session
+        .current_player()
+        .map(|player| player.name.clone())
+        .unwrap_or_default();
+
+    html! {
+        <div class="screen">
+            <div class="round-clear">
+                <p>{ format!("{} / {}", props.session.current + 1, props.session.players.len()) }</p>
+                <h1>{ format!("{}님 차례예요", name) }</h1>
+                <p>{ "휴대폰을 넘겨주세요 📱" }</p>
+            </div>
+            <button onclick={props.on_ready.clone()} class="start-button">{ "준비됐어요" }</button>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PartyStandingsProps {
+    pub session: PartySession,
+    pub on_replay: Callback<MouseEvent>,
+    pub on_back: Callback<MouseEvent>,
+}
+
+/// 파티 모드 최종 순위 화면
+#[function_component(PartyStandings)]
+pub fn party_standings(props: &PartyStandingsProps) -> Html {
+    let rows = props.session.standings().into_iter().enumerate().map(|(rank, player)| {
+        let (score, time_used, device) = match &player.result {
+            Some(result) if result.assisted => (format!("{}점", result.score), format_time(result.time_used), format!("{} · 보조", result.pointer_type.label())),
+            Some(result) => (format!("{}점", result.score), format_time(result.time_used), result.pointer_type.label().to_string()),
+            None => ("-".to_string(), "-".to_string(), String::new()),
+        };
+
+        html! {
+            <li class={classes!("standing-row", (rank == 0).then_some("winner"))}>
+                <span class="standing-rank">{ rank + 1 }</span>
+                <span class="standing-name">{ player.name.clone() }</span>
+                <span class="standing-score">{ score }</span>
+                <span class="standing-time">{ time_used }</span>
+                <span class="standing-device">{ device }</span>
+            </li>
+        }
+    });
+
+    html! {
+        <div class="screen">
+            <div class="sentence">
+                <h3>{ "최종 순위 🏆" }</h3>
+            </div>
+            <ol class="standings">
+                { for rows }
+            </ol>
+            <button class="back-link standings-back" onclick={props.on_back.clone()}>{ "처음으로" }</button>
+            <button onclick={props.on_replay.clone()} class="start-button">{ "같은 멤버로 다시 하기" }</button>
+        </div>
+    }
+}
+
+fn default_name(index: usize) -> String {
+    format!("플레이어 {}", index + 1)
+}
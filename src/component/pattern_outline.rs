@@ -0,0 +1,28 @@
+use crate::pattern::Outline;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct PatternOutlineProps {
+    pub outline: Outline,
+    #[prop_or_default]
+    pub reveal: bool, // 결과 화면에서 그린 선 위에 가이드를 겹쳐 공개
+}
+
+/// 패턴 가이드 외곽선
+#[function_component(PatternOutline)]
+pub fn pattern_outline(props: &PatternOutlineProps) -> Html {
+    match (&props.outline, props.reveal) {
+        (Outline::Filled(d), false) => html! {
+            <path d={*d} fill="white" fill-opacity="0.4"/>
+        },
+        (Outline::Filled(d), true) => html! {
+            <path class="guide-reveal" d={*d} fill="#FFF983" />
+        },
+        (Outline::Stroked(d), false) => html! {
+            <path d={d.clone()} stroke="white" stroke-opacity="0.4" stroke-width="8" stroke-linejoin="round" fill="none"/>
+        },
+        (Outline::Stroked(d), true) => html! {
+            <path class="guide-reveal-stroke" d={d.clone()} stroke="#FFF983" stroke-width="8" stroke-linejoin="round" fill="none"/>
+        },
+    }
+}
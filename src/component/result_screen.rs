@@ -1,4 +1,14 @@
-use crate::func::format_time;
+use crate::component::pattern_outline::PatternOutline;
+use crate::component::stroke_polyline::{PressureStroke, StrokePolyline};
+use crate::component::tree_decorations::{DecorationPalette, TreeDecorations};
+use crate::component::trophy_screen::AchievementToast;
+use crate::achievement::Achievement;
+use crate::decoration::{DecoratedTree, Decoration, UNLOCK_SCORE};
+use crate::func::{format_time, path_to_points};
+use crate::app::TimeMode;
+use crate::ghost::GhostStroke;
+use crate::pattern::Pattern;
+use crate::stroke::{points, StrokeSample};
 use crate::upload;
 use crate::share;
 use yew::prelude::*;
@@ -6,9 +16,40 @@ use yew::prelude::*;
 #[derive(Properties, PartialEq)]
 pub struct ResultScreenProps {
     pub score: u32,
-    pub result_path: Vec<(f64, f64)>, // 사용자가 그린 점의 좌표
+    pub pattern: Pattern,
+    pub reveal_guide: bool, // 기억 모드: 가이드를 그린 선 위에 겹쳐 공개
+    pub result_path: Vec<StrokeSample>, // 사용자가 그린 점 (좌표, 시각, 필압 등)
     pub on_retry: Callback<MouseEvent>,
+    #[prop_or(true)]
+    pub retryable: bool, // 파티, 토너먼트는 차례마다 한 번만 그리므로 다시 도전하기 숨김
     pub remaining_time: f64,
+    pub time_mode: TimeMode,
+    #[prop_or_default]
+    pub title: Option<AttrValue>, // 상단 문구 (캠페인 목표, 파티 플레이어 이름 등)
+    #[prop_or_default]
+    pub next_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub on_next: Option<Callback<MouseEvent>>, // 다시 도전하기 옆에 표시할 다음 단계 버튼
+    #[prop_or_default]
+    pub on_level_select: Option<Callback<MouseEvent>>,
+    #[prop_or_default]
+    pub ghost: Option<GhostStroke>, // 함께 달린 친구의 기록 (나란히 비교)
+    #[prop_or_default]
+    pub challenge_code: Option<AttrValue>, // 내 기록을 담은 도전장 코드
+    #[prop_or(true)]
+    pub shareable: bool, // 연습 모드에서는 공유 버튼 숨김
+    #[prop_or_default]
+    pub on_back: Option<Callback<MouseEvent>>,
+    #[prop_or_default]
+    pub new_achievements: Vec<&'static Achievement>, // 이번 라운드에 달성한 업적 (알림 표시)
+    #[prop_or_default]
+    pub interrupted: bool, // 브라우저 제스처로 선이 끊긴 라운드
+    #[prop_or_default]
+    pub out_of_bounds_time: f64, // 그리는 영역 밖에 머문 시간 (밀리초)
+    #[prop_or_default]
+    pub left_play_area: bool, // 영역 이탈 실패 규칙으로 0점
+    #[prop_or_default]
+    pub assisted: bool, // 보조 모드로 그린 결과 (공유 문구에도 표시)
 }
 
 #[function_component(ResultScreen)]
@@ -18,13 +59,11 @@ pub fn result_screen(props: &ResultScreenProps) -> Html {
     let image_url = use_state(|| None::<String>); // 업로드된 이미지 URL 상태
     let viewer_url = use_state(|| None::<String>); // 업로드된 이미지 URL 상태
     let is_processing = use_state(|| false); // 처리 상태 추가
+    let decorations = use_state(Vec::<Decoration>::new); // 트리에 붙인 장식
+    let is_decorating = use_state(|| false); // 꾸미기 팔레트 표시 상태
+    let tree_ref = use_node_ref();
 
-    let path_points = props
-        .result_path
-        .iter()
-        .map(|(x, y)| format!("{},{}", x, y))
-        .collect::<Vec<_>>()
-        .join(" ");
+    let path_points = points(&props.result_path);
 
 
     // 공유 버튼 핸들러
@@ -34,6 +73,8 @@ pub fn result_screen(props: &ResultScreenProps) -> Html {
         let image_url = image_url.clone();
         let viewer_url = viewer_url.clone();
         let score = props.score;
+        let result_path = path_points.clone();
+        let decorations = decorations.clone();
 
         Callback::from(move |_: MouseEvent| {
             if *is_processing {
@@ -47,12 +88,17 @@ pub fn result_screen(props: &ResultScreenProps) -> Html {
                 let is_share_section_visible = is_share_section_visible.clone();
                 let image_url = image_url.clone();
                 let viewer_url = viewer_url.clone();
+                // 꾸민 트리는 공유 이미지에 함께 그림
+                let tree = (!decorations.is_empty()).then(|| DecoratedTree {
+                    path: result_path.clone(),
+                    decorations: (*decorations).clone(),
+                });
 
                 async move {
                     web_sys::console::log_1(&"Starting canvas rendering...".into());
 
                     // 캔버스 렌더링
-                    let data_url = match upload::render_canvas(score).await {
+                    let data_url = match upload::render_canvas(score, tree).await {
                         Ok(data_url) => {
                             web_sys::console::log_1(&"Canvas rendered successfully.".into());
                             data_url
@@ -90,21 +136,27 @@ pub fn result_screen(props: &ResultScreenProps) -> Html {
         let kakao_url = image_url.as_ref().map(|url| url.clone()).unwrap_or_default();
         let viewer_url = viewer_url.clone();
         let score = props.score.to_string();
+        let time_label = if props.assisted {
+            format!("{} · 보조 모드", props.time_mode.label())
+        } else {
+            props.time_mode.label().to_string()
+        };
+        let link_url = share::challenge_url(props.challenge_code.as_deref());
 
         Callback::from(move |platform: String| {
             if let Some(url) = &*viewer_url {
                 match platform.as_str() {
                     "facebook" => {
-                        share::share_to_facebook(url, &score);
+                        share::share_to_facebook(url, &link_url, &score, &time_label);
                     }
                     "twitter" => {
-                        share::share_to_twitter(url, &score);
+                        share::share_to_twitter(url, &link_url, &score, &time_label);
                     }
                     "kakao" => {
-                        share::share_to_kakao(&kakao_url, &score);
+                        share::share_to_kakao(&kakao_url, &link_url, &score, &time_label);
                     }
                     "link" => {
-                        share::copy_to_link(url, &score);
+                        share::copy_to_link(url, &link_url, &score, &time_label);
                     }
                     _ => {}
                 }
@@ -117,8 +169,32 @@ pub fn result_screen(props: &ResultScreenProps) -> Html {
     
     html! {
         <div class="screen">
+            <AchievementToast achievements={props.new_achievements.clone()} />
             <div class="result-sentence">
-                <h3>{ "친구에게 도전장을 보내보세요!" }</h3>
+                <h3>{ props.title.clone().unwrap_or_else(|| AttrValue::from("친구에게 도전장을 보내보세요!")) }</h3>
+                {
+                    if props.interrupted {
+                        html! { <p class="interrupted-notice">{ "브라우저 동작(스크롤, 뒤로 가기 등)으로 선이 끊겼어요" }</p> }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    if props.assisted {
+                        html! { <p class="assisted-badge">{ "보조 모드 기록" }</p> }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    if props.left_play_area {
+                        html! { <p class="interrupted-notice">{ "그리는 영역을 벗어나 실패했어요" }</p> }
+                    } else if props.out_of_bounds_time > 0.0 {
+                        html! { <p class="out-of-bounds-notice">{ format!("영역 밖 {:.1}초 (채점 제외)", props.out_of_bounds_time / 1000.0) }</p> }
+                    } else {
+                        html! {}
+                    }
+                }
             </div>            
             <div class="score">
                 <svg class="score-background" viewBox="0 0 125 67" fill="none" xmlns="http://www.w3.org/2000/svg">
@@ -152,47 +228,180 @@ pub fn result_screen(props: &ResultScreenProps) -> Html {
                     preserveAspectRatio="xMidYMin"
                     fill="none"
                     xmlns="http://www.w3.org/2000/svg"
+                    ref={tree_ref.clone()}
                     >
-                    <path d="M121.15 8.50157L117.724 6.43576V6.43576L121.15 8.50157ZM134.851 8.50158L131.425 10.5674V10.5674L134.851 8.50158ZM35.8845 149.877L39.3097 151.942L35.8845 149.877ZM68.6952 162.008L72.1781 163.975C72.8776 162.737 72.8668 161.22 72.1498 159.992C71.4329 158.763 70.1175 158.008 68.6952 158.008V162.008ZM5.04591 274.704L1.56301 272.737H1.56301L5.04591 274.704ZM250.954 274.704L254.437 272.737L250.954 274.704ZM187.305 162.008V158.008C185.882 158.008 184.567 158.763 183.85 159.992C183.133 161.22 183.122 162.737 183.822 163.975L187.305 162.008ZM220.116 149.877L216.69 151.942V151.942L220.116 149.877ZM124.575 10.5674C126.13 7.98848 129.87 7.98849 131.425 10.5674L138.276 6.43576C133.61 -1.30098 122.39 -1.30097 117.724 6.43576L124.575 10.5674ZM39.3097 151.942L124.575 10.5674L117.724 6.43576L32.4592 147.811L39.3097 151.942ZM42.735 158.008C39.6217 158.008 37.7019 154.608 39.3097 151.942L32.4592 147.811C27.6356 155.809 33.3952 166.008 42.735 166.008V158.008ZM68.6952 158.008H42.735V166.008H68.6952V158.008ZM8.5288 276.671L72.1781 163.975L65.2123 160.041L1.56301 272.737L8.5288 276.671ZM12.0117 282.638C8.94932 282.638 7.0228 279.338 8.5288 276.671L1.56301 272.737C-2.95499 280.737 2.82455 290.638 12.0117 290.638V282.638ZM243.988 282.638H12.0117V290.638H243.988V282.638ZM247.471 276.671C248.977 279.338 247.051 282.638 243.988 282.638V290.638C253.175 290.638 258.955 280.737 254.437 272.737L247.471 276.671ZM183.822 163.975L247.471 276.671L254.437 272.737L190.788 160.041L183.822 163.975ZM213.265 158.008H187.305V166.008H213.265V158.008ZM216.69 151.942C218.298 154.608 216.378 158.008 213.265 158.008V166.008C222.605 166.008 228.364 155.809 223.541 147.811L216.69 151.942ZM131.425 10.5674L216.69 151.942L223.541 147.811L138.276 6.43576L131.425 10.5674Z" fill="white" fill-opacity="0.4"/>
+                    <PatternOutline outline={props.pattern.outline.clone()} />
 
-                    <polyline
-                        points={path_points}
-                        stroke="#72F48F"
-                        stroke-width="8"
-                        fill="none"
-                    />
+                    <PressureStroke samples={props.result_path.clone()} />
+
+                    {
+                        if props.reveal_guide {
+                            html! { <PatternOutline outline={props.pattern.outline.clone()} reveal=true /> }
+                        } else {
+                            html! {}
+                        }
+                    }
+
+                    <TreeDecorations decorations={(*decorations).clone()} />
 
                 </svg>
 
-                <button onclick={props.on_retry.clone()} class="retry-button">
-                        <svg class="retry-icon" fill="none" xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMidYMin" >
-                        <path d="M12.9225 6.83334C12.5225 6.83334 12.2559 7.10001 12.2559 7.50001C12.2559 9.43334 11.2559 11.1667 9.58919 12.1C7.05586 13.5667 3.78919 12.7 2.32253 10.1667C0.855859 7.63334 1.72253 4.36668 4.25586 2.90001C6.45586 1.63334 9.12253 2.10001 10.7892 3.83334H9.18919C8.78919 3.83334 8.52253 4.10001 8.52253 4.50001C8.52253 4.90001 8.78919 5.16668 9.18919 5.16668H12.1892C12.5892 5.16668 12.8559 4.90001 12.8559 4.50001V1.50001C12.8559 1.10001 12.5892 0.833344 12.1892 0.833344C11.7892 0.833344 11.5225 1.10001 11.5225 1.50001V2.70001C10.2559 1.50001 8.65586 0.833344 6.92253 0.833344C3.25586 0.833344 0.255859 3.83334 0.255859 7.50001C0.255859 11.1667 3.25586 14.1667 6.92253 14.1667C10.5892 14.1667 13.5892 11.1667 13.5892 7.50001C13.5892 7.10001 13.3225 6.83334 12.9225 6.83334Z" fill="#72F58F"/>
-                        </svg>
-                    { "다시 도전하기" }
-                </button>
+                <div class="result-actions">
+                    {
+                        if props.retryable {
+                            html! {
+                                <button onclick={props.on_retry.clone()} class="retry-button">
+                                        <svg class="retry-icon" fill="none" xmlns="http://www.w3.org/2000/svg" preserveAspectRatio="xMidYMin" >
+                                        <path d="M12.9225 6.83334C12.5225 6.83334 12.2559 7.10001 12.2559 7.50001C12.2559 9.43334 11.2559 11.1667 9.58919 12.1C7.05586 13.5667 3.78919 12.7 2.32253 10.1667C0.855859 7.63334 1.72253 4.36668 4.25586 2.90001C6.45586 1.63334 9.12253 2.10001 10.7892 3.83334H9.18919C8.78919 3.83334 8.52253 4.10001 8.52253 4.50001C8.52253 4.90001 8.78919 5.16668 9.18919 5.16668H12.1892C12.5892 5.16668 12.8559 4.90001 12.8559 4.50001V1.50001C12.8559 1.10001 12.5892 0.833344 12.1892 0.833344C11.7892 0.833344 11.5225 1.10001 11.5225 1.50001V2.70001C10.2559 1.50001 8.65586 0.833344 6.92253 0.833344C3.25586 0.833344 0.255859 3.83334 0.255859 7.50001C0.255859 11.1667 3.25586 14.1667 6.92253 14.1667C10.5892 14.1667 13.5892 11.1667 13.5892 7.50001C13.5892 7.10001 13.3225 6.83334 12.9225 6.83334Z" fill="#72F58F"/>
+                                        </svg>
+                                    { "다시 도전하기" }
+                                </button>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    {
+                        if let Some(on_next) = &props.on_next {
+                            html! {
+                                <button onclick={on_next.clone()} class="retry-button">
+                                    { props.next_label.clone().unwrap_or_default() }
+                                </button>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+
+                {
+                    if let Some(on_level_select) = &props.on_level_select {
+                        html! {
+                            <button onclick={on_level_select.clone()} class="level-select-link">
+                                { "레벨 선택" }
+                            </button>
+                        }
+                    } else if let Some(on_back) = &props.on_back {
+                        html! {
+                            <button onclick={on_back.clone()} class="level-select-link">
+                                { "처음으로" }
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
 
                 <div class="timer">
                     { format_time(props.remaining_time) }
                 </div>
             </div>
-            <button class="start-button" onclick={share_handler} disabled={*is_processing}>
-                { if *is_processing { "조금만 기다려 주세요 🥹" } else { "도전장 보내기" } }
-             </button>
-            <div id="share-section" class={if *is_share_section_visible { "share-section show" } else { "share-section hidden" }}>
-                <div class="share-container">
-                <div class="share-text">{ "🌲 친구에게 도전장 보내기 🌲" }</div>
-                    <div class="icons">
-                        <button class="icon-button" onclick={share_to_platform.reform(|_| "facebook".to_string())}><img src="image/facebook-icon.png" alt="Facebook"/></button>
-                        <button class="icon-button" onclick={share_to_platform.reform(|_| "twitter".to_string())}><img src="image/x-icon.png" alt="Twitter" /></button>
-                        <button class="icon-button" onclick={share_to_platform.reform(|_| "kakao".to_string())}><img src="image/kakao-icon.png" alt="Kakao" /></button>
-                        <button class="icon-button" onclick={share_to_platform.reform(|_| "link".to_string())}><img src="image/link-icon.png" alt="Link" /></button>
-                    </div>
-                </div>
-            </div>
+            {
+                if let Some(ghost) = &props.ghost {
+                    ghost_compare(props, ghost)
+                } else {
+                    html! {}
+                }
+            }
+            {
+                if *is_decorating {
+                    let on_place = {
+                        let decorations = decorations.clone();
+                        Callback::from(move |decoration: Decoration| {
+                            let mut updated = (*decorations).clone();
+                            updated.push(decoration);
+                            decorations.set(updated);
+                        })
+                    };
+                    let on_undo = {
+                        let decorations = decorations.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let mut updated = (*decorations).clone();
+                            updated.pop();
+                            decorations.set(updated);
+                        })
+                    };
+                    let on_done = {
+                        let is_decorating = is_decorating.clone();
+                        Callback::from(move |_: MouseEvent| is_decorating.set(false))
+                    };
+
+                    html! {
+                        <DecorationPalette
+                            board={tree_ref.clone()}
+                            outline={path_points.clone()}
+                            {on_place}
+                            {on_undo}
+                            {on_done}
+                        />
+                    }
+                } else if props.score >= UNLOCK_SCORE {
+                    let on_decorate = {
+                        let is_decorating = is_decorating.clone();
+                        Callback::from(move |_: MouseEvent| is_decorating.set(true))
+                    };
+                    html! { <button class="back-link decorate-link" onclick={on_decorate}>{ "🎄 트리 꾸미기" }</button> }
+                } else {
+                    html! {}
+                }
+            }
+            {
+                if props.shareable && !*is_decorating {
+                    html! {
+                        <>
+                            <button class="start-button" onclick={share_handler} disabled={*is_processing}>
+                                { if *is_processing { "조금만 기다려 주세요 🥹" } else { "도전장 보내기" } }
+                             </button>
+                            <div id="share-section" class={if *is_share_section_visible { "share-section show" } else { "share-section hidden" }}>
+                                <div class="share-container">
+                                <div class="share-text">{ "🌲 친구에게 도전장 보내기 🌲" }</div>
+                                    <div class="icons">
+                                        <button class="icon-button" onclick={share_to_platform.reform(|_| "facebook".to_string())}><img src="image/facebook-icon.png" alt="Facebook"/></button>
+                                        <button class="icon-button" onclick={share_to_platform.reform(|_| "twitter".to_string())}><img src="image/x-icon.png" alt="Twitter" /></button>
+                                        <button class="icon-button" onclick={share_to_platform.reform(|_| "kakao".to_string())}><img src="image/kakao-icon.png" alt="Kakao" /></button>
+                                        <button class="icon-button" onclick={share_to_platform.reform(|_| "link".to_string())}><img src="image/link-icon.png" alt="Link" /></button>
+                                    </div>
+                                </div>
+                            </div>
+                        </>
+                    }
+                } else {
+                    html! {}
+                }
+            }
         </div>
         }
 }
 
+// 친구 고스트와 나란히 비교
+fn ghost_compare(props: &ResultScreenProps, ghost: &GhostStroke) -> Html {
+    let verdict = match props.score.cmp(&ghost.score) {
+        std::cmp::Ordering::Greater => "친구를 이겼어요! 🎉",
+        std::cmp::Ordering::Equal => "친구와 비겼어요!",
+        std::cmp::Ordering::Less => "아쉽게 졌어요 🥲",
+    };
+
+    let card = |label: &str, score: u32, points: String, class: &'static str| html! {
+        <div class="ghost-card">
+            <svg class="ghost-mini" viewBox="0 0 256 291" preserveAspectRatio="xMidYMin" fill="none" xmlns="http://www.w3.org/2000/svg">
+                <PatternOutline outline={props.pattern.outline.clone()} />
+                <StrokePolyline {class} {points} />
+            </svg>
+            <div class="ghost-card-label">{ format!("{} {}점", label, score) }</div>
+        </div>
+    };
+
+    html! {
+        <div class="ghost-compare">
+            <div class="ghost-verdict">{ verdict }</div>
+            <div class="ghost-cards">
+                { card("나", props.score, path_to_points(&points(&props.result_path)), "") }
+                { card("친구", ghost.score, path_to_points(&ghost.path()), "ghost-stroke") }
+            </div>
+        </div>
+    }
+}
+
 // 노란색 별 SVG
 fn yellow_star() -> Html {
     html! {
@@ -1,20 +1,163 @@
+use crate::app::{Difficulty, GameMode, TimeMode};
+use crate::assist::AssistSettings;
+use crate::component::pattern_outline::PatternOutline;
+use crate::component::tutorial_demo::TutorialDemo;
+use crate::func::format_time;
+use crate::pattern::Pattern;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct StartScreenProps {
     pub on_start: Callback<MouseEvent>,
+    pub difficulty: Difficulty,
+    pub on_select_difficulty: Callback<Difficulty>,
+    pub pattern: Pattern,
+    pub game_mode: GameMode,
+    pub on_select_game_mode: Callback<GameMode>,
+    pub time_mode: TimeMode,
+    pub on_select_time_mode: Callback<TimeMode>,
+    pub use_countdown: bool,
+    pub on_toggle_countdown: Callback<MouseEvent>,
+    pub fail_out_of_bounds: bool, // 영역을 벗어나면 바로 실패
+    pub on_toggle_bounds_rule: Callback<MouseEvent>,
+    pub assist: AssistSettings, // 보조 모드 설정
+    pub on_change_assist: Callback<AssistSettings>,
+    pub show_tutorial: bool, // 시범 영상 재생 여부
+    pub on_show_tutorial: Callback<MouseEvent>,
+    pub on_tutorial_finish: Callback<()>,
+    pub on_open_campaign: Callback<MouseEvent>,
+    pub on_start_survival: Callback<MouseEvent>,
+    pub on_open_party: Callback<MouseEvent>,
+    pub on_start_practice: Callback<MouseEvent>,
+    pub on_open_tournament: Callback<MouseEvent>,
+    pub on_open_trophies: Callback<MouseEvent>,
+    pub on_open_stats: Callback<MouseEvent>,
+    #[prop_or_default]
+    pub on_start_duel: Option<Callback<MouseEvent>>, // 태블릿에서만 표시
+    #[prop_or_default]
+    pub ghost_score: Option<u32>, // 도전장 링크로 받은 친구 점수
+    pub on_clear_ghost: Callback<MouseEvent>,
 }
 
 #[function_component(StartScreen)]
 pub fn start_screen(props: &StartScreenProps) -> Html {
+    // 난이도 선택 버튼
+    let difficulty_buttons = Difficulty::ALL.iter().map(|difficulty| {
+        let difficulty = *difficulty;
+        let class = if difficulty == props.difficulty { "difficulty-button selected" } else { "difficulty-button" };
+        html! {
+            <button {class} onclick={props.on_select_difficulty.reform(move |_| difficulty)}>
+                { difficulty.label() }
+            </button>
+        }
+    });
+
+    // 제한 시간 선택 버튼
+    let time_mode_buttons = TimeMode::ALL.iter().map(|time_mode| {
+        let time_mode = *time_mode;
+        let class = if time_mode == props.time_mode { "difficulty-button selected" } else { "difficulty-button" };
+        html! {
+            <button {class} onclick={props.on_select_time_mode.reform(move |_| time_mode)}>
+                { time_mode.label() }
+            </button>
+        }
+    });
+
+    // 게임 모드 선택 버튼
+    let memory_mode = match props.game_mode {
+        GameMode::Memory { .. } => props.game_mode,
+        GameMode::Classic => GameMode::Memory { preview_ms: GameMode::MEMORY_PREVIEW_OPTIONS[1] },
+    };
+    let mode_buttons = [GameMode::Classic, memory_mode].into_iter().map(|game_mode| {
+        let selected = std::mem::discriminant(&game_mode) == std::mem::discriminant(&props.game_mode);
+        let class = if selected { "difficulty-button selected" } else { "difficulty-button" };
+        html! {
+            <button {class} onclick={props.on_select_game_mode.reform(move |_| game_mode)}>
+                { game_mode.label() }
+            </button>
+        }
+    });
+
+    // 기억 모드 미리보기 시간 선택 버튼
+    let preview_buttons = match props.game_mode {
+        GameMode::Memory { preview_ms: current } => GameMode::MEMORY_PREVIEW_OPTIONS
+            .iter()
+            .map(|preview_ms| {
+                let preview_ms = *preview_ms;
+                let class = if preview_ms == current { "difficulty-button selected" } else { "difficulty-button" };
+                html! {
+                    <button {class} onclick={props.on_select_game_mode.reform(move |_| GameMode::Memory { preview_ms })}>
+                        { format!("{}초 보기", preview_ms / 1000) }
+                    </button>
+                }
+            })
+            .collect::<Html>(),
+        GameMode::Classic => html! {},
+    };
+
+    // 보조 모드 켜기와 세부 설정 버튼 (켜져 있을 때만 세부 설정 표시)
+    let assist = props.assist;
+    let assist_button = |label: String, selected: bool, settings: AssistSettings| {
+        let class = if selected { "difficulty-button selected" } else { "difficulty-button" };
+        html! {
+            <button {class} aria-pressed={selected.to_string()} onclick={props.on_change_assist.reform(move |_| settings)}>
+                { label }
+            </button>
+        }
+    };
+    let assist_options = if assist.enabled {
+        AssistSettings::TIME_SCALES
+            .iter()
+            .map(|time_scale| {
+                let time_scale = *time_scale;
+                assist_button(format!("시간 ×{}", time_scale), assist.time_scale == time_scale, AssistSettings { time_scale, ..assist })
+            })
+            .chain([
+                assist_button("가이드 자석".to_string(), assist.snap, AssistSettings { snap: !assist.snap, ..assist }),
+                assist_button("키보드".to_string(), assist.keyboard, AssistSettings { keyboard: !assist.keyboard, ..assist }),
+            ])
+            .collect::<Html>()
+    } else {
+        html! {}
+    };
+
     html! {
         <div class="screen">
+            <div class="menu-bar">
+                <button class="menu-button" onclick={props.on_open_campaign.clone()}>{ "캠페인" }</button>
+                <button class="menu-button" onclick={props.on_start_survival.clone()}>{ "서바이벌" }</button>
+                <button class="menu-button" onclick={props.on_open_party.clone()}>{ "파티" }</button>
+                <button class="menu-button" onclick={props.on_open_tournament.clone()}>{ "토너먼트" }</button>
+                <button class="menu-button" onclick={props.on_start_practice.clone()}>{ "연습" }</button>
+                <button class="menu-button" onclick={props.on_open_trophies.clone()}>{ "업적" }</button>
+                <button class="menu-button" onclick={props.on_open_stats.clone()}>{ "기록" }</button>
+                {
+                    if let Some(on_start_duel) = &props.on_start_duel {
+                        html! { <button class="menu-button" onclick={on_start_duel.clone()}>{ "듀얼" }</button> }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+
+            {
+                if let Some(ghost_score) = props.ghost_score {
+                    html! {
+                        <div class="ghost-banner">
+                            <span>{ format!("친구의 도전장이 도착했어요! 친구 {}점", ghost_score) }</span>
+                            <button class="ghost-banner-close" onclick={props.on_clear_ghost.clone()}>{ "✕" }</button>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
 
             <div class="sentence">
                 <h1>
                     <span class="highlight">{"트리"}</span>{" 그리기 챌린지"}
                 </h1>
-                <p>{ "5초 안에 트리를 그려보세요!" }</p>
+                <p>{ props.time_mode.description() }</p>
             </div>
             <div class="tree-container">
 
@@ -22,12 +165,61 @@ pub fn start_screen(props: &StartScreenProps) -> Html {
                     <path d="M24.3204 1.598C25.1084 0.379102 26.8916 0.379101 27.6796 1.598L35.1592 13.1676C35.429 13.585 35.8447 13.887 36.3251 14.0146L49.6397 17.553C51.0425 17.9258 51.5935 19.6216 50.6778 20.7477L41.9858 31.4364C41.6722 31.8221 41.5134 32.3107 41.5404 32.807L42.2897 46.5634C42.3687 48.0127 40.9261 49.0608 39.5721 48.5379L26.7206 43.5743C26.2569 43.3952 25.7431 43.3952 25.2794 43.5743L12.4279 48.5379C11.0739 49.0608 9.63133 48.0127 9.71027 46.5634L10.4596 32.807C10.4866 32.3107 10.3278 31.8221 10.0142 31.4364L1.32224 20.7477C0.40651 19.6216 0.957531 17.9258 2.36028 17.553L15.6749 14.0146C16.1553 13.887 16.571 13.585 16.8408 13.1676L24.3204 1.598Z" fill="#FFFFFF29"/>
                 </svg>
 
-                <svg class="tree-pattern" viewBox="0 0 256 291" preserveAspectRatio="xMidYMin" fill="none" xmlns="http://www.w3.org/2000/svg">
-                    <path d="M121.15 8.50157L117.724 6.43576V6.43576L121.15 8.50157ZM134.851 8.50158L131.425 10.5674V10.5674L134.851 8.50158ZM35.8845 149.877L39.3097 151.942L35.8845 149.877ZM68.6952 162.008L72.1781 163.975C72.8776 162.737 72.8668 161.22 72.1498 159.992C71.4329 158.763 70.1175 158.008 68.6952 158.008V162.008ZM5.04591 274.704L1.56301 272.737H1.56301L5.04591 274.704ZM250.954 274.704L254.437 272.737L250.954 274.704ZM187.305 162.008V158.008C185.882 158.008 184.567 158.763 183.85 159.992C183.133 161.22 183.122 162.737 183.822 163.975L187.305 162.008ZM220.116 149.877L216.69 151.942V151.942L220.116 149.877ZM124.575 10.5674C126.13 7.98848 129.87 7.98849 131.425 10.5674L138.276 6.43576C133.61 -1.30098 122.39 -1.30097 117.724 6.43576L124.575 10.5674ZM39.3097 151.942L124.575 10.5674L117.724 6.43576L32.4592 147.811L39.3097 151.942ZM42.735 158.008C39.6217 158.008 37.7019 154.608 39.3097 151.942L32.4592 147.811C27.6356 155.809 33.3952 166.008 42.735 166.008V158.008ZM68.6952 158.008H42.735V166.008H68.6952V158.008ZM8.5288 276.671L72.1781 163.975L65.2123 160.041L1.56301 272.737L8.5288 276.671ZM12.0117 282.638C8.94932 282.638 7.0228 279.338 8.5288 276.671L1.56301 272.737C-2.95499 280.737 2.82455 290.638 12.0117 290.638V282.638ZM243.988 282.638H12.0117V290.638H243.988V282.638ZM247.471 276.671C248.977 279.338 247.051 282.638 243.988 282.638V290.638C253.175 290.638 258.955 280.737 254.437 272.737L247.471 276.671ZM183.822 163.975L247.471 276.671L254.437 272.737L190.788 160.041L183.822 163.975ZM213.265 158.008H187.305V166.008H213.265V158.008ZM216.69 151.942C218.298 154.608 216.378 158.008 213.265 158.008V166.008C222.605 166.008 228.364 155.809 223.541 147.811L216.69 151.942ZM131.425 10.5674L216.69 151.942L223.541 147.811L138.276 6.43576L131.425 10.5674Z" fill="white" fill-opacity="0.4"/>
-                </svg>
+                {
+                    if props.show_tutorial {
+                        html! {
+                            <TutorialDemo
+                                pattern={props.pattern.clone()}
+                                on_finish={props.on_tutorial_finish.clone()}
+                            />
+                        }
+                    } else {
+                        html! {
+                            <>
+                                <svg class="tree-pattern" viewBox="0 0 256 291" preserveAspectRatio="xMidYMin" fill="none" xmlns="http://www.w3.org/2000/svg">
+                                    <PatternOutline outline={props.pattern.outline.clone()} />
+                                </svg>
+
+                                <button class="tutorial-button" onclick={props.on_show_tutorial.clone()}>
+                                    { "시범 보기" }
+                                </button>
+                            </>
+                        }
+                    }
+                }
 
                 <div class="timer">
-                    { "05 : 00" }
+                    { format_time(assist.scale_duration(props.time_mode.initial_time())) }
+                </div>
+            </div>
+            <div class="option-select">
+                <div class="difficulty-select">
+                    { for mode_buttons }
+                </div>
+                <div class="difficulty-select">
+                    { preview_buttons }
+                </div>
+                <div class="difficulty-select">
+                    { for time_mode_buttons }
+                    <button
+                        class={if props.use_countdown { "difficulty-button selected" } else { "difficulty-button" }}
+                        onclick={props.on_toggle_countdown.clone()}
+                        >
+                        { "3-2-1" }
+                    </button>
+                    <button
+                        class={if props.fail_out_of_bounds { "difficulty-button selected" } else { "difficulty-button" }}
+                        onclick={props.on_toggle_bounds_rule.clone()}
+                        >
+                        { "이탈 시 실패" }
+                    </button>
+                </div>
+                <div class="difficulty-select">
+                    { for difficulty_buttons }
+                </div>
+                <div class="difficulty-select assist-select">
+                    { assist_button("보조 모드".to_string(), assist.enabled, AssistSettings { enabled: !assist.enabled, ..assist }) }
+                    { assist_options }
                 </div>
             </div>
             <button onclick={props.on_start.clone()} class="start-button">{ "도전하기" }</button>
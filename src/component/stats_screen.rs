@@ -0,0 +1,92 @@
+use crate::stats::{month_days, year_month, DailyRecord};
+use yew::prelude::*;
+
+const WEEKDAYS: [&str; 7] = ["일", "월", "화", "수", "목", "금", "토"];
+
+#[derive(Properties, PartialEq)]
+pub struct StatsScreenProps {
+    pub record: DailyRecord,
+    pub today: AttrValue, // "YYYY-MM-DD"
+    pub on_back: Callback<MouseEvent>,
+}
+
+/// 연속 플레이 기록과 날짜별 최고 점수 달력
+#[function_component(StatsScreen)]
+pub fn stats_screen(props: &StatsScreenProps) -> Html {
+    let (this_year, this_month) = year_month(&props.today).unwrap_or((1970, 1));
+    let shown = use_state(|| (this_year, this_month)); // 달력에 표시 중인 연, 월
+    let (year, month) = *shown;
+
+    let on_previous = {
+        let shown = shown.clone();
+        Callback::from(move |_: MouseEvent| {
+            shown.set(if month == 1 { (year - 1, 12) } else { (year, month - 1) });
+        })
+    };
+    let on_next = {
+        let shown = shown.clone();
+        Callback::from(move |_: MouseEvent| {
+            shown.set(if month == 12 { (year + 1, 1) } else { (year, month + 1) });
+        })
+    };
+
+    let (first_weekday, dates) = month_days(year, month);
+    let blanks = (0..first_weekday).map(|_| html! { <span class="calendar-day empty" /> });
+    let days = dates.iter().enumerate().map(|(index, date)| {
+        let record = props.record.get(date);
+        // 최고 점수에 따라 색 단계 (0: 플레이 안 함 ~ 4: 90점 이상)
+        let level = record.map_or(0, |day| match day.best_score {
+            90.. => 4,
+            70..=89 => 3,
+            40..=69 => 2,
+            _ => 1,
+        });
+        let title = record
+            .map(|day| format!("{} · 최고 {}점 · {}판", date, day.best_score, day.rounds))
+            .unwrap_or_else(|| date.clone());
+
+        html! {
+            <span
+                class={classes!("calendar-day", format!("level-{}", level), (*date == props.today.as_str()).then_some("today"))}
+                {title}
+                >
+                { index + 1 }
+            </span>
+        }
+    });
+
+    html! {
+        <div class="screen">
+            <div class="sentence">
+                <h3>{ "나의 기록" }</h3>
+            </div>
+            <div class="streak-summary">
+                <div>
+                    <p class="streak-value">{ format!("🔥 {}일", props.record.current_streak(&props.today)) }</p>
+                    <p class="streak-label">{ "연속 플레이" }</p>
+                </div>
+                <div>
+                    <p class="streak-value">{ format!("{}일", props.record.best_streak()) }</p>
+                    <p class="streak-label">{ "최장 연속" }</p>
+                </div>
+                <div>
+                    <p class="streak-value">{ format!("{}일", props.record.days.len()) }</p>
+                    <p class="streak-label">{ "플레이한 날" }</p>
+                </div>
+            </div>
+            <div class="calendar">
+                <div class="calendar-header">
+                    <button class="back-link" onclick={on_previous}>{ "◀" }</button>
+                    <span>{ format!("{}년 {}월", year, month) }</span>
+                    <button class="back-link" onclick={on_next}>{ "▶" }</button>
+                </div>
+                <div class="calendar-grid">
+                    { for WEEKDAYS.iter().map(|weekday| html! { <span class="calendar-weekday">{ *weekday }</span> }) }
+                    { for blanks }
+                    { for days }
+                </div>
+            </div>
+            <button onclick={props.on_back.clone()} class="start-button">{ "처음으로" }</button>
+        </div>
+    }
+}
@@ -0,0 +1,66 @@
+use crate::func::path_to_points;
+use crate::stroke::{points, PointerKind, StrokeSample};
+use yew::prelude::*;
+
+pub const STROKE_COLOR: &str = "#72F48F";
+pub const STROKE_WIDTH: f64 = 8.0;
+
+#[derive(Properties, PartialEq)]
+pub struct StrokePolylineProps {
+    #[prop_or_default]
+    pub points: String, // polyline의 points 속성 문자열
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// 사용자가 그린 선 (그리기 화면, 결과 화면, 시범 영상에서 공통 사용)
+#[function_component(StrokePolyline)]
+pub fn stroke_polyline(props: &StrokePolylineProps) -> Html {
+    html! {
+        <polyline
+            class={props.class.clone()}
+            points={props.points.clone()}
+            stroke={STROKE_COLOR}
+            stroke-width={STROKE_WIDTH.to_string()}
+            fill="none"
+        />
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PressureStrokeProps {
+    pub samples: Vec<StrokeSample>,
+}
+
+/// 필압을 반영한 선 (펜으로 그린 경우 구간마다 굵기가 달라짐)
+#[function_component(PressureStroke)]
+pub fn pressure_stroke(props: &PressureStrokeProps) -> Html {
+    let uses_pressure = props
+        .samples
+        .iter()
+        .any(|sample| sample.pointer_type == PointerKind::Pen);
+    if !uses_pressure {
+        return html! { <StrokePolyline points={path_to_points(&points(&props.samples))} /> };
+    }
+
+    let segments = props.samples.windows(2).map(|segment| {
+        let width = STROKE_WIDTH * (segment[0].width_scale() + segment[1].width_scale()) / 2.0;
+        html! {
+            <line
+                x1={segment[0].x.to_string()}
+                y1={segment[0].y.to_string()}
+                x2={segment[1].x.to_string()}
+                y2={segment[1].y.to_string()}
+                stroke={STROKE_COLOR}
+                stroke-width={format!("{:.2}", width)}
+                stroke-linecap="round"
+            />
+        }
+    });
+
+    html! {
+        <g class="pressure-stroke">
+            { for segments }
+        </g>
+    }
+}
@@ -0,0 +1,54 @@
+use crate::survival::SurvivalRun;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct RoundClearProps {
+    pub run: SurvivalRun,
+    pub score: u32,
+}
+
+/// 라운드 통과 후 다음 라운드 시작 전 잠깐 보여주는 화면
+#[function_component(RoundClear)]
+pub fn round_clear(props: &RoundClearProps) -> Html {
+    html! {
+        <div class="screen">
+            <div class="round-clear">
+                <h1>{ format!("{}점 통과!", props.score) }</h1>
+                <p>{ format!("곧 {}라운드가 시작됩니다", props.run.round + 1) }</p>
+                <p>{ format!("누적 {}점", props.run.total_score) }</p>
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SurvivalOverProps {
+    pub run: SurvivalRun,
+    pub last_score: u32,
+    pub on_retry: Callback<MouseEvent>,
+    pub on_back: Callback<MouseEvent>,
+}
+
+/// 서바이벌 모드 최종 결과 화면
+#[function_component(SurvivalOver)]
+pub fn survival_over(props: &SurvivalOverProps) -> Html {
+    html! {
+        <div class="screen">
+            <div class="sentence">
+                <h3>{ format!("{}라운드에서 {}점으로 탈락!", props.run.round + 1, props.last_score) }</h3>
+            </div>
+            <div class="survival-summary">
+                <div class="summary-item">
+                    <span class="summary-value">{ props.run.rounds_survived() }</span>
+                    <span class="summary-label">{ "생존 라운드" }</span>
+                </div>
+                <div class="summary-item">
+                    <span class="summary-value">{ props.run.total_score }</span>
+                    <span class="summary-label">{ "누적 점수" }</span>
+                </div>
+                <button onclick={props.on_back.clone()} class="back-link">{ "처음으로" }</button>
+            </div>
+            <button onclick={props.on_retry.clone()} class="start-button">{ "다시 도전하기" }</button>
+        </div>
+    }
+}
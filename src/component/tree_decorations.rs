@@ -0,0 +1,143 @@
+use crate::component::drawing_board::to_view_box;
+use crate::decoration::{Decoration, DecorationKind};
+use web_sys::{Element, PointerEvent, SvgElement};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct TreeDecorationsProps {
+    pub decorations: Vec<Decoration>,
+}
+
+/// 트리 위에 붙인 장식 (SVG 안에서 사용)
+#[function_component(TreeDecorations)]
+pub fn tree_decorations(props: &TreeDecorationsProps) -> Html {
+    let items = props.decorations.iter().map(|decoration| {
+        let fill = decoration.kind.color();
+        match decoration.kind {
+            DecorationKind::Topper => {
+                let points = decoration
+                    .star_points()
+                    .iter()
+                    .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                html! { <polygon class="decoration topper" {points} {fill} /> }
+            }
+            kind => html! {
+                <circle
+                    class={classes!("decoration", (kind == DecorationKind::Light).then_some("light"))}
+                    cx={decoration.x.to_string()}
+                    cy={decoration.y.to_string()}
+                    r={kind.radius().to_string()}
+                    {fill}
+                />
+            },
+        }
+    });
+
+    html! {
+        <g class="decorations">
+            { for items }
+        </g>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DecorationPaletteProps {
+    pub board: NodeRef,             // 장식을 놓을 트리 SVG
+    pub outline: Vec<(f64, f64)>,   // 사용자가 그린 선 (장식 위치 제한)
+    pub on_place: Callback<Decoration>,
+    pub on_undo: Callback<MouseEvent>,
+    pub on_done: Callback<MouseEvent>,
+}
+
+/// 장식을 끌어다 트리에 놓는 팔레트
+#[function_component(DecorationPalette)]
+pub fn decoration_palette(props: &DecorationPaletteProps) -> Html {
+    let dragging = use_state(|| None::<(DecorationKind, f64, f64)>); // 끌고 있는 장식과 화면 좌표
+    let rejected = use_state(|| false);                              // 트리 밖에 놓았는지
+
+    let items = DecorationKind::ALL.iter().map(|kind| {
+        let kind = *kind;
+
+        // 누른 버튼이 포인터를 캡처하므로 팔레트 밖으로 끌어도 계속 추적됨
+        let on_pointer_down = {
+            let dragging = dragging.clone();
+            let rejected = rejected.clone();
+            Callback::from(move |event: PointerEvent| {
+                if let Some(target) = event.target_dyn_into::<Element>() {
+                    let _ = target.set_pointer_capture(event.pointer_id());
+                }
+                rejected.set(false);
+                dragging.set(Some((kind, event.client_x() as f64, event.client_y() as f64)));
+            })
+        };
+
+        let on_pointer_move = {
+            let dragging = dragging.clone();
+            Callback::from(move |event: PointerEvent| {
+                if dragging.is_some() {
+                    dragging.set(Some((kind, event.client_x() as f64, event.client_y() as f64)));
+                }
+            })
+        };
+
+        let on_pointer_up = {
+            let dragging = dragging.clone();
+            let rejected = rejected.clone();
+            let board = props.board.clone();
+            let outline = props.outline.clone();
+            let on_place = props.on_place.clone();
+            Callback::from(move |event: PointerEvent| {
+                dragging.set(None);
+                let Some(svg) = board.cast::<SvgElement>() else {
+                    return;
+                };
+
+                match Decoration::place(kind, to_view_box(&svg, &event), &outline) {
+                    Some(decoration) => on_place.emit(decoration),
+                    None => rejected.set(true),
+                }
+            })
+        };
+
+        html! {
+            <button
+                class="palette-item"
+                onpointerdown={on_pointer_down}
+                onpointermove={on_pointer_move}
+                onpointerup={on_pointer_up}
+                >
+                <span class="palette-swatch" style={format!("background-color: {}", kind.color())} />
+                { kind.label() }
+            </button>
+        }
+    });
+
+    html! {
+        <div class="decoration-palette">
+            <p class="palette-hint">
+                { if *rejected { "트리 안쪽에 놓아 주세요" } else { "장식을 끌어서 트리에 달아 보세요" } }
+            </p>
+            <div class="palette-items">
+                { for items }
+            </div>
+            <div class="palette-actions">
+                <button class="back-link" onclick={props.on_undo.clone()}>{ "되돌리기" }</button>
+                <button class="back-link" onclick={props.on_done.clone()}>{ "완료" }</button>
+            </div>
+            {
+                if let Some((kind, x, y)) = *dragging {
+                    html! {
+                        <span
+                            class="drag-preview"
+                            style={format!("left: {}px; top: {}px; background-color: {}", x, y, kind.color())}
+                        />
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
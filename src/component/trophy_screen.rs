@@ -0,0 +1,67 @@
+use crate::achievement::{Achievement, AchievementProgress, ACHIEVEMENTS};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct TrophyScreenProps {
+    pub progress: AchievementProgress,
+    pub on_back: Callback<MouseEvent>,
+}
+
+/// 업적 목록 화면
+#[function_component(TrophyScreen)]
+pub fn trophy_screen(props: &TrophyScreenProps) -> Html {
+    let unlocked_count = ACHIEVEMENTS
+        .iter()
+        .filter(|achievement| props.progress.is_unlocked(achievement))
+        .count();
+
+    let trophies = ACHIEVEMENTS.iter().map(|achievement| {
+        let unlocked = props.progress.is_unlocked(achievement);
+        html! {
+            <li class={classes!("trophy", unlocked.then_some("unlocked"))}>
+                <span class="trophy-icon">{ if unlocked { "🏆" } else { "🔒" } }</span>
+                <span class="trophy-title">{ achievement.title }</span>
+                <span class="trophy-description">{ achievement.description }</span>
+            </li>
+        }
+    });
+
+    html! {
+        <div class="screen">
+            <div class="sentence">
+                <h3>{ format!("업적 {} / {}", unlocked_count, ACHIEVEMENTS.len()) }</h3>
+            </div>
+            <ul class="trophy-list">
+                { for trophies }
+            </ul>
+            <button onclick={props.on_back.clone()} class="start-button">{ "처음으로" }</button>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AchievementToastProps {
+    pub achievements: Vec<&'static Achievement>,
+}
+
+/// 새로 달성한 업적 알림 (잠시 표시된 뒤 사라짐)
+#[function_component(AchievementToast)]
+pub fn achievement_toast(props: &AchievementToastProps) -> Html {
+    let toasts = props.achievements.iter().enumerate().map(|(index, achievement)| {
+        html! {
+            <div class="achievement-toast" style={format!("animation-delay: {}ms", index * 400)}>
+                <span class="trophy-icon">{ "🏆" }</span>
+                <div>
+                    <div class="trophy-title">{ format!("업적 달성: {}", achievement.title) }</div>
+                    <div class="trophy-description">{ achievement.description }</div>
+                </div>
+            </div>
+        }
+    });
+
+    html! {
+        <div class="achievement-toasts">
+            { for toasts }
+        </div>
+    }
+}
@@ -0,0 +1,81 @@
+use crate::component::pattern_outline::PatternOutline;
+use crate::component::stroke_polyline::StrokePolyline;
+use crate::func::{now, path_to_points};
+use crate::pattern::Pattern;
+use gloo::timers::callback::Interval;
+use yew::prelude::*;
+
+// 시범 영상에서 선을 그리는 속도 (밀리초당 viewBox 좌표, 기본 트리는 약 4초)
+const DEMO_SPEED: f64 = 0.25;
+// 다 그린 뒤 완성된 모양을 보여주는 시간 (밀리초)
+const HOLD_DURATION: f64 = 800.0;
+const FRAME_INTERVAL: u32 = 16;
+
+#[derive(Properties, PartialEq)]
+pub struct TutorialDemoProps {
+    pub pattern: Pattern,
+    pub on_finish: Callback<()>,
+}
+
+/// 패턴을 따라 선을 그리는 시범 영상
+#[function_component(TutorialDemo)]
+pub fn tutorial_demo(props: &TutorialDemoProps) -> Html {
+    let elapsed = use_state(|| 0.0);
+    // 패턴 길이에 비례한 실제 그리는 속도로 재생 (길이가 0인 패턴도 나눌 수 있도록 최소 1ms)
+    let duration = (props.pattern.length() / DEMO_SPEED).max(1.0);
+
+    {
+        let elapsed = elapsed.clone();
+        let on_finish = props.on_finish.clone();
+
+        use_effect_with(props.pattern.clone(), move |_| {
+            let start_time = now();
+            let mut finished = false;
+            let interval = Interval::new(FRAME_INTERVAL, move || {
+                if finished {
+                    return;
+                }
+                let time = now() - start_time;
+                elapsed.set(time);
+
+                // 다 그린 뒤 잠시 보여주고 한 번만 종료 알림
+                if time >= duration + HOLD_DURATION {
+                    finished = true;
+                    on_finish.emit(());
+                }
+            });
+
+            move || drop(interval)
+        });
+    }
+
+    let traced = props.pattern.trace(*elapsed / duration);
+    let hand = traced.last().copied();
+
+    html! {
+        <>
+            <svg class="tree-pattern"
+                 viewBox="0 0 256 291"
+                 preserveAspectRatio="xMidYMin"
+                 fill="none"
+                 xmlns="http://www.w3.org/2000/svg"
+                 >
+                <PatternOutline outline={props.pattern.outline.clone()} />
+
+                <StrokePolyline points={path_to_points(&traced)} />
+
+                {
+                    if let Some((x, y)) = hand {
+                        html! { <circle class="demo-hand" cx={x.to_string()} cy={y.to_string()} r="12" /> }
+                    } else {
+                        html! {}
+                    }
+                }
+            </svg>
+
+            <button class="skip-button" onclick={props.on_finish.reform(|_| ())}>
+                { "건너뛰기" }
+            </button>
+        </>
+    }
+}
@@ -0,0 +1,157 @@
+// 꾸미기를 열 수 있는 최소 점수
+pub const UNLOCK_SCORE: u32 = 70;
+
+// 별 장식은 그린 선의 꼭대기 근처에만 놓을 수 있음
+const TOPPER_SNAP_DISTANCE: f64 = 40.0;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DecorationKind {
+    Ornament,
+    Light,
+    Topper,
+}
+
+impl DecorationKind {
+    pub const ALL: [DecorationKind; 3] = [DecorationKind::Ornament, DecorationKind::Light, DecorationKind::Topper];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DecorationKind::Ornament => "오너먼트",
+            DecorationKind::Light => "전구",
+            DecorationKind::Topper => "별",
+        }
+    }
+
+    pub fn color(&self) -> &'static str {
+        match self {
+            DecorationKind::Ornament => "#FF7A7A",
+            DecorationKind::Light => "#FFF983",
+            DecorationKind::Topper => "#FFD84D",
+        }
+    }
+
+    /// 장식 크기 (viewBox 기준 반지름)
+    pub fn radius(&self) -> f64 {
+        match self {
+            DecorationKind::Ornament => 8.0,
+            DecorationKind::Light => 4.0,
+            DecorationKind::Topper => 16.0,
+        }
+    }
+}
+
+/// 트리에 붙인 장식 하나 (viewBox 좌표)
+#[derive(Clone, Copy, PartialEq)]
+pub struct Decoration {
+    pub kind: DecorationKind,
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Decoration {
+    /// 사용자가 그린 트리에 장식 놓기
+    ///
+    /// 오너먼트와 전구는 그린 선 안쪽에만 놓을 수 있고, 별은 가장 높은 점에 붙습니다.
+    /// 놓을 수 없는 위치면 `None`을 반환합니다.
+    pub fn place(kind: DecorationKind, point: (f64, f64), outline: &[(f64, f64)]) -> Option<Self> {
+        let (x, y) = match kind {
+            DecorationKind::Topper => {
+                let top = outline.iter().copied().min_by(|a, b| a.1.total_cmp(&b.1))?;
+                let distance = ((point.0 - top.0).powi(2) + (point.1 - top.1).powi(2)).sqrt();
+                (distance <= TOPPER_SNAP_DISTANCE).then_some(top)?
+            }
+            _ => point_in_polygon(point, outline).then_some(point)?,
+        };
+
+        Some(Self { kind, x, y })
+    }
+
+    /// 별 장식의 꼭짓점 (바깥/안쪽 번갈아 10개)
+    pub fn star_points(&self) -> Vec<(f64, f64)> {
+        let radius = self.kind.radius();
+        (0..10)
+            .map(|index| {
+                let length = if index % 2 == 0 { radius } else { radius * 0.45 };
+                let angle = std::f64::consts::PI * index as f64 / 5.0 - std::f64::consts::FRAC_PI_2;
+                (self.x + length * angle.cos(), self.y + length * angle.sin())
+            })
+            .collect()
+    }
+}
+
+/// 공유 이미지에 그릴 꾸민 트리
+#[derive(Clone, PartialEq)]
+pub struct DecoratedTree {
+    pub path: Vec<(f64, f64)>, // 사용자가 그린 선
+    pub decorations: Vec<Decoration>,
+}
+
+/// 점이 다각형 안에 있는지 확인 (광선 교차 횟수, 마지막 점과 첫 점은 자동으로 이어짐)
+pub fn point_in_polygon(point: (f64, f64), polygon: &[(f64, f64)]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    let mut previous = polygon[polygon.len() - 1];
+    for &current in polygon {
+        let crosses = (current.1 > point.1) != (previous.1 > point.1);
+        if crosses {
+            let x = previous.0 + (point.1 - previous.1) / (current.1 - previous.1) * (current.0 - previous.0);
+            if point.0 < x {
+                inside = !inside;
+            }
+        }
+        previous = current;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pattern::Pattern;
+
+    const SQUARE: [(f64, f64); 4] = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+
+    #[test]
+    fn square_contains_inner_point() {
+        assert!(point_in_polygon((5.0, 5.0), &SQUARE));
+        assert!(!point_in_polygon((15.0, 5.0), &SQUARE));
+        assert!(!point_in_polygon((5.0, -1.0), &SQUARE));
+    }
+
+    #[test]
+    fn concave_notch_is_outside() {
+        // 위쪽 가운데가 파인 U자 모양
+        let polygon = [
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 6.0),
+            (6.0, 6.0),
+            (6.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (0.0, 10.0),
+        ];
+
+        assert!(!point_in_polygon((5.0, 3.0), &polygon));
+        assert!(point_in_polygon((2.0, 3.0), &polygon));
+        assert!(point_in_polygon((5.0, 8.0), &polygon));
+    }
+
+    #[test]
+    fn tree_pattern_contains_point_below_top() {
+        let outline = Pattern::tree().points;
+        let top = outline.iter().copied().min_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+
+        assert!(point_in_polygon((top.0, top.1 + 40.0), &outline));
+        assert!(!point_in_polygon((2.0, 2.0), &outline));
+    }
+
+    #[test]
+    fn degenerate_polygon_is_empty() {
+        assert!(!point_in_polygon((0.0, 0.0), &[]));
+        assert!(!point_in_polygon((1.0, 0.0), &[(0.0, 0.0), (2.0, 0.0)]));
+    }
+}
@@ -0,0 +1,134 @@
+use wasm_bindgen::JsValue;
+use web_sys::js_sys::Reflect;
+use web_sys::{window, Window};
+
+// 트리를 그릴 수 있는 최소 화면 짧은 변 (CSS 픽셀)
+const MIN_SHORT_SIDE: f64 = 280.0;
+// 두 사람이 나란히 그릴 수 있는 화면 짧은 변
+const DUEL_SHORT_SIDE: f64 = 600.0;
+
+/// 브라우저 기능 기반 기기 호환성 보고서
+///
+/// 사용자 에이전트 문자열 대신 실제로 사용할 수 있는 입력 방식과 화면 크기로 판단합니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviceReport {
+    pub pointer_events: bool,  // Pointer Events 지원 (마우스, 펜, 터치 공통 입력)
+    pub coarse_pointer: bool,  // 주 입력이 손가락 같은 굵은 포인터 (pointer: coarse)
+    pub any_fine_pointer: bool, // 마우스나 펜 같은 정밀한 포인터가 하나라도 있음
+    pub max_touch_points: i32, // 동시에 인식하는 터치 개수
+    pub orientation_api: bool, // 화면 방향 변경 감지 지원
+    pub width: f64,            // 창 크기 (CSS 픽셀)
+    pub height: f64,
+}
+
+/// 보고서 항목 하나 (화면에 그대로 보여줄 설명)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Check {
+    pub label: &'static str,
+    pub passed: bool,
+    pub required: bool, // 통과하지 못하면 플레이할 수 없는 항목
+    pub detail: String,
+}
+
+impl DeviceReport {
+    pub fn detect() -> Self {
+        let Some(window) = window() else {
+            return Self::unavailable();
+        };
+
+        let width = window.inner_width().ok().and_then(|width| width.as_f64()).unwrap_or(0.0);
+        let height = window.inner_height().ok().and_then(|height| height.as_f64()).unwrap_or(0.0);
+        let screen = Reflect::get(&window, &JsValue::from_str("screen")).unwrap_or(JsValue::UNDEFINED);
+        let orientation_api = Reflect::has(&screen, &JsValue::from_str("orientation")).unwrap_or(false)
+            || Reflect::has(&window, &JsValue::from_str("onorientationchange")).unwrap_or(false);
+
+        Self {
+            pointer_events: Reflect::has(&window, &JsValue::from_str("PointerEvent")).unwrap_or(false),
+            coarse_pointer: matches_media(&window, "(pointer: coarse)"),
+            any_fine_pointer: matches_media(&window, "(any-pointer: fine)"),
+            max_touch_points: window.navigator().max_touch_points(),
+            orientation_api,
+            width,
+            height,
+        }
+    }
+
+    fn unavailable() -> Self {
+        Self {
+            pointer_events: false,
+            coarse_pointer: false,
+            any_fine_pointer: false,
+            max_touch_points: 0,
+            orientation_api: false,
+            width: 0.0,
+            height: 0.0,
+        }
+    }
+
+    fn short_side(&self) -> f64 {
+        self.width.min(self.height)
+    }
+
+    /// 게임을 플레이할 수 있는지 (필수 항목을 모두 통과)
+    pub fn is_supported(&self) -> bool {
+        self.checks().iter().all(|check| check.passed || !check.required)
+    }
+
+    /// 한 화면에서 두 명이 동시에 그릴 수 있는지 (듀얼 모드)
+    pub fn supports_duel(&self) -> bool {
+        self.max_touch_points >= 2 && self.short_side() >= DUEL_SHORT_SIDE
+    }
+
+    /// 화면에 보여줄 항목별 결과
+    pub fn checks(&self) -> Vec<Check> {
+        let input = match (self.coarse_pointer, self.any_fine_pointer) {
+            (true, true) => "터치와 마우스/펜",
+            (true, false) => "터치",
+            (false, true) => "마우스/펜",
+            (false, false) => "알 수 없음",
+        };
+
+        vec![
+            Check {
+                label: "포인터 입력",
+                passed: self.pointer_events,
+                required: true,
+                detail: if self.pointer_events {
+                    format!("{} 입력으로 그릴 수 있어요", input)
+                } else {
+                    "브라우저가 Pointer Events를 지원하지 않아요".to_string()
+                },
+            },
+            Check {
+                label: "화면 크기",
+                passed: self.short_side() >= MIN_SHORT_SIDE,
+                required: true,
+                detail: format!("{:.0} × {:.0} (짧은 변 {:.0}px 이상 필요)", self.width, self.height, MIN_SHORT_SIDE),
+            },
+            Check {
+                label: "화면 방향 감지",
+                passed: self.orientation_api,
+                required: false,
+                detail: if self.orientation_api {
+                    "화면을 돌리면 자동으로 맞춰요".to_string()
+                } else {
+                    "창 크기로 방향을 판단해요".to_string()
+                },
+            },
+            Check {
+                label: "듀얼 모드",
+                passed: self.supports_duel(),
+                required: false,
+                detail: format!("동시 터치 {}개 (2개 이상, 짧은 변 {:.0}px 이상 필요)", self.max_touch_points, DUEL_SHORT_SIDE),
+            },
+        ]
+    }
+}
+
+fn matches_media(window: &Window, query: &str) -> bool {
+    window
+        .match_media(query)
+        .ok()
+        .flatten()
+        .is_some_and(|media| media.matches())
+}
@@ -0,0 +1,73 @@
+use crate::func::ScoreBreakdown;
+use crate::party::compare_results;
+use crate::stroke::{StrokeBuffer, StrokeSample};
+use std::cmp::Ordering;
+
+pub const PLAYER_NAMES: [&str; 2] = ["왼쪽", "오른쪽"];
+
+/// 태블릿 한 화면에서 두 명이 동시에 그리는 듀얼 모드 세션
+#[derive(Clone, Default, PartialEq)]
+pub struct DuelSession {
+    pub strokes: [StrokeBuffer; 2],
+    pub started_at: [Option<f64>; 2], // 플레이어별 첫 터치 시각
+    pub time_used: [f64; 2],
+    pub finished: [bool; 2],
+    pub interrupted: [bool; 2], // 브라우저 제스처로 선이 끊김
+    pub left_area: [bool; 2],   // 영역 이탈 실패 규칙으로 끝남
+    pub paths: [Vec<StrokeSample>; 2],
+    pub results: [Option<ScoreBreakdown>; 2],
+}
+
+impl DuelSession {
+    /// 새 라운드를 위해 그린 경로와 결과 초기화
+    pub fn reset(&mut self) {
+        for stroke in &self.strokes {
+            stroke.clear();
+        }
+        self.started_at = [None; 2];
+        self.time_used = [0.0; 2];
+        self.finished = [false; 2];
+        self.interrupted = [false; 2];
+        self.left_area = [false; 2];
+        self.paths = Default::default();
+        self.results = Default::default();
+    }
+
+    pub fn start(&mut self, player: usize, now: f64) {
+        if self.started_at[player].is_none() {
+            self.started_at[player] = Some(now);
+        }
+    }
+
+    pub fn finish(&mut self, player: usize, now: f64) {
+        if !self.finished[player] {
+            self.finished[player] = true;
+            self.time_used[player] = self.started_at[player].map_or(0.0, |start| now - start);
+        }
+    }
+
+    /// 화면 회전으로 멈춰 있던 시간을 걸린 시간에서 제외
+    pub fn resume(&mut self, paused_for: f64) {
+        for (started_at, finished) in self.started_at.iter_mut().zip(self.finished) {
+            if let (Some(start), false) = (started_at.as_mut(), finished) {
+                *start += paused_for;
+            }
+        }
+    }
+
+    pub fn all_finished(&self) -> bool {
+        self.finished.iter().all(|finished| *finished)
+    }
+
+    /// 승자 인덱스 (무승부면 `None`)
+    pub fn winner(&self) -> Option<usize> {
+        match (&self.results[0], &self.results[1]) {
+            (Some(left), Some(right)) => match compare_results(left, right) {
+                Ordering::Less => Some(0),
+                Ordering::Greater => Some(1),
+                Ordering::Equal => None,
+            },
+            _ => None,
+        }
+    }
+}
@@ -1,28 +1,190 @@
 
+use crate::stroke::{out_of_bounds_time, PointerKind, StrokeSample};
+use serde::{Deserialize, Serialize};
+
+/// 채점 세부 결과
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    pub score: u32,           // 0 ~ 100 사이 점수
+    pub passed_points: usize, // 통과한 패턴 점 개수
+    pub total_points: usize,
+    pub time_used: f64,       // 첫 터치부터 손을 뗄 때까지 걸린 시간 (밀리초)
+    pub mean_deviation: f64,  // 그린 점과 패턴 선 사이의 평균 거리 (낮을수록 정밀)
+    #[serde(default)]
+    pub pointer_type: PointerKind, // 입력 장치 (장치별 순위 구분용)
+    #[serde(default)]
+    pub interrupted: bool, // 브라우저 제스처(스크롤, 뒤로 가기 등)로 선이 끊김
+    #[serde(default)]
+    pub out_of_bounds_time: f64, // 그리는 영역 밖에 머문 시간 (밀리초)
+    #[serde(default)]
+    pub left_play_area: bool, // 영역 이탈 실패 규칙으로 0점 처리됨
+    #[serde(default)]
+    pub assisted: bool, // 보조 모드(느린 타이머, 가이드 끌어당기기, 키보드 입력)로 그림
+}
+
 /// 주요 패턴 점 통과 여부 기반 점수 계산
-pub fn calculate_score(user_path: &[(f64, f64)], pattern: &[(f64, f64)], threshold: f64) -> u32 {
-    let mut passed_points = 0;
-
-    for pattern_point in pattern {
-        if user_path.iter().any(|user_point| {
-            let dx = user_point.0 - pattern_point.0;
-            let dy = user_point.1 - pattern_point.1;
-            (dx.powi(2) + dy.powi(2)).sqrt() <= threshold
-        }) {
-            passed_points += 1;
-        }
+fn calculate_score(passed_points: usize, total_points: usize) -> u32 {
+    let percentage = passed_points as f64 / total_points as f64;
+    (percentage * 100.0).round() as u32 // 0 ~ 100 사이 점수
+}
+
+/// 점수와 함께 시간, 정밀도 등 세부 결과 계산
+///
+/// 그리는 영역 밖 샘플은 채점에서 제외하고 머문 시간만 따로 기록합니다.
+pub fn score_breakdown(
+    samples: &[StrokeSample],
+    pattern: &[(f64, f64)],
+    threshold: f64,
+    time_used: f64,
+) -> ScoreBreakdown {
+    let user_path = &samples
+        .iter()
+        .filter(|sample| !sample.out_of_bounds)
+        .map(StrokeSample::point)
+        .collect::<Vec<_>>();
+    let mean_deviation = if user_path.is_empty() {
+        0.0
+    } else {
+        user_path
+            .iter()
+            .map(|point| distance_to_pattern(*point, pattern).0)
+            .sum::<f64>()
+            / user_path.len() as f64
+    };
+    let passed_points = count_passed_points(user_path, pattern, threshold);
+
+    ScoreBreakdown {
+        score: calculate_score(passed_points, pattern.len()),
+        passed_points,
+        total_points: pattern.len(),
+        time_used,
+        mean_deviation,
+        pointer_type: samples.first().map(|sample| sample.pointer_type).unwrap_or_default(),
+        interrupted: false,
+        out_of_bounds_time: out_of_bounds_time(samples),
+        left_play_area: false,
+        assisted: false,
     }
+}
 
-    // 점수 계산
-    let percentage = passed_points as f64 / pattern.len() as f64;
-    (percentage * 100.0).round() as u32 // 0 ~ 100 사이 점수
+/// 사용자 경로가 `threshold` 이내로 지나간 패턴 점 개수
+fn count_passed_points(user_path: &[(f64, f64)], pattern: &[(f64, f64)], threshold: f64) -> usize {
+    pattern
+        .iter()
+        .filter(|pattern_point| {
+            user_path.iter().any(|user_point| {
+                let dx = user_point.0 - pattern_point.0;
+                let dy = user_point.1 - pattern_point.1;
+                (dx.powi(2) + dy.powi(2)).sqrt() <= threshold
+            })
+        })
+        .count()
+}
+
+/// 점에서 패턴 선까지의 최단 거리와 가장 가까운 선분의 인덱스
+pub fn distance_to_pattern(point: (f64, f64), pattern: &[(f64, f64)]) -> (f64, usize) {
+    pattern
+        .windows(2)
+        .enumerate()
+        .map(|(index, segment)| (distance_to_segment(point, segment[0], segment[1]), index))
+        .fold((f64::INFINITY, 0), |nearest, candidate| {
+            if candidate.0 < nearest.0 { candidate } else { nearest }
+        })
+}
+
+fn distance_to_segment(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (nearest_x, nearest_y) = nearest_point_on_segment(point, start, end);
+    ((point.0 - nearest_x).powi(2) + (point.1 - nearest_y).powi(2)).sqrt()
+}
+
+/// 선분 위에서 `point`와 가장 가까운 점
+pub fn nearest_point_on_segment(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> (f64, f64) {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_squared = dx * dx + dy * dy;
+
+    // 선분 위 가장 가까운 점의 위치 (0.0 ~ 1.0)
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+
+    (start.0 + t * dx, start.1 + t * dy)
+}
+
+/// 연습 모드에서 선 색상 (허용 거리 안: 초록, 두 배 안: 노랑, 그 밖: 빨강)
+pub fn deviation_color(deviation: f64, tolerance: f64) -> &'static str {
+    if deviation <= tolerance {
+        "#72F48F"
+    } else if deviation <= tolerance * 2.0 {
+        "#FFF983"
+    } else {
+        "#FF7A7A"
+    }
 }
 
 pub fn format_time(milliseconds: f64) -> String {
-    let total_seconds = (milliseconds / 1000.0).floor() as u32; // 밀리초를 초로 변환
+    let hundredths = (milliseconds / 10.0).round() as u32; // 1/100초 단위로 반올림한 뒤 나눔 (999ms → 1초)
+    let total_seconds = hundredths / 100; // 초로 변환
+    let minutes = total_seconds / 60; // 분 계산 (젠 모드)
     let seconds = total_seconds % 60; // 초 계산
-    let millis = ((milliseconds % 1000.0) / 10.0).round() as u32; // 밀리초를 두 자리로 변환
+    let millis = hundredths % 100; // 밀리초를 두 자리로 변환
+
+    // 두 자리로 포맷팅: "04 : 35" 형식, 1분 이상이면 "1:04 : 35" 형식
+    if minutes > 0 {
+        format!("{}:{:02} : {:02}", minutes, seconds, millis)
+    } else {
+        format!("{:02} : {:02}", seconds, millis)
+    }
+}
+
+/// 좌표 목록을 polyline의 points 속성 문자열로 변환
+pub fn path_to_points(path: &[(f64, f64)]) -> String {
+    path.iter()
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 그린 경로의 방향 (viewBox는 y축이 아래로 향하므로 양수면 화면에서 시계 방향)
+pub fn is_clockwise(path: &[(f64, f64)]) -> bool {
+    let signed_area: f64 = path
+        .iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum();
+    signed_area > 0.0
+}
 
-    // 두 자리로 포맷팅: "04 : 35" 형식
-    format!("{:02} : {:02}", seconds, millis)
+/// 페이지가 열린 뒤 흐른 시간 (밀리초, 애니메이션 재생용)
+pub fn now() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now())
+        .unwrap_or(0.0)
+}
+
+/// 오늘 날짜 (로컬 시간, "YYYY-MM-DD")
+pub fn today() -> String {
+    let date = web_sys::js_sys::Date::new_0();
+    format!("{:04}-{:02}-{:02}", date.get_full_year(), date.get_month() + 1, date.get_date())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_time_rounds_to_hundredths() {
+        assert_eq!(format_time(4354.0), "04 : 35");
+        assert_eq!(format_time(4356.0), "04 : 36");
+        assert_eq!(format_time(0.0), "00 : 00");
+    }
+
+    #[test]
+    fn format_time_carries_into_seconds_and_minutes() {
+        assert_eq!(format_time(999.0), "01 : 00");
+        assert_eq!(format_time(59_999.0), "1:00 : 00");
+        assert_eq!(format_time(60_995.0), "1:01 : 00");
+    }
 }
@@ -0,0 +1,199 @@
+use crate::pattern::{VIEW_BOX_HEIGHT, VIEW_BOX_WIDTH};
+use crate::stroke::StrokeSample;
+
+// 도전장 링크에 담을 최대 좌표 개수
+const MAX_SAMPLES: usize = 150;
+const FORMAT_VERSION: u8 = 1;
+const TIME_UNIT: f64 = 10.0; // 시간 간격 저장 단위 (밀리초)
+
+/// 친구가 그린 선 (좌표와 시각)을 그대로 재생하는 고스트
+#[derive(Clone, Debug, PartialEq)]
+pub struct GhostStroke {
+    pub samples: Vec<(f64, f64, f64)>, // (x, y, 첫 터치부터 경과 시간)
+    pub score: u32,
+}
+
+impl GhostStroke {
+    /// 그린 경로로 고스트 생성 (링크 길이를 위해 좌표 수를 줄임)
+    pub fn new(stroke: &[StrokeSample], score: u32) -> Self {
+        let start = stroke.first().map_or(0.0, |sample| sample.t);
+        let samples: Vec<(f64, f64, f64)> = stroke
+            .iter()
+            .map(|sample| (sample.x, sample.y, sample.t - start))
+            .collect();
+        let samples = if samples.len() <= MAX_SAMPLES {
+            samples
+        } else {
+            let last = samples.len() - 1;
+            (0..MAX_SAMPLES)
+                .map(|index| samples[index * last / (MAX_SAMPLES - 1)])
+                .collect()
+        };
+
+        Self { samples, score }
+    }
+
+    /// 전체 경로
+    pub fn path(&self) -> Vec<(f64, f64)> {
+        self.samples.iter().map(|(x, y, _)| (*x, *y)).collect()
+    }
+
+    /// `elapsed` 밀리초까지 그려진 경로
+    pub fn path_until(&self, elapsed: f64) -> Vec<(f64, f64)> {
+        self.samples
+            .iter()
+            .take_while(|(_, _, time)| *time <= elapsed)
+            .map(|(x, y, _)| (*x, *y))
+            .collect()
+    }
+
+    /// 도전장 링크용 문자열로 변환
+    ///
+    /// 좌표 하나당 3바이트 (x, y, 이전 좌표와의 시간 간격)로 저장합니다.
+    pub fn encode(&self) -> String {
+        let mut bytes = vec![FORMAT_VERSION, self.score.min(100) as u8];
+        let mut previous_time = 0.0;
+
+        for (x, y, time) in &self.samples {
+            let delta = ((time - previous_time) / TIME_UNIT).round().clamp(0.0, 255.0) as u8;
+            previous_time += delta as f64 * TIME_UNIT;
+            bytes.push((x / VIEW_BOX_WIDTH * 255.0).round().clamp(0.0, 255.0) as u8);
+            bytes.push((y / VIEW_BOX_HEIGHT * 255.0).round().clamp(0.0, 255.0) as u8);
+            bytes.push(delta);
+        }
+
+        base64_encode(&bytes)
+    }
+
+    /// 도전장 링크 문자열에서 고스트 복원
+    pub fn decode(code: &str) -> Option<Self> {
+        let bytes = base64_decode(code)?;
+        let (header, body) = bytes.split_at_checked(2)?;
+        // 점수가 100을 넘으면 손으로 고친 링크로 보고 거절
+        if header[0] != FORMAT_VERSION || header[1] > 100 || body.len() % 3 != 0 {
+            return None;
+        }
+
+        let mut time = 0.0;
+        let samples = body
+            .chunks(3)
+            .map(|chunk| {
+                time += chunk[2] as f64 * TIME_UNIT;
+                (
+                    chunk[0] as f64 / 255.0 * VIEW_BOX_WIDTH,
+                    chunk[1] as f64 / 255.0 * VIEW_BOX_HEIGHT,
+                    time,
+                )
+            })
+            .collect();
+
+        Some(Self {
+            samples,
+            score: header[1] as u32,
+        })
+    }
+}
+
+/// 현재 페이지 주소의 `?ghost=` 값에서 고스트 읽기
+pub fn load_from_location() -> Option<GhostStroke> {
+    let search = web_sys::window()?.location().search().ok()?;
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|param| param.strip_prefix("ghost="))
+        .and_then(GhostStroke::decode)
+}
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// URL에 안전한 base64 인코딩 (패딩 없음)
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len() * 4 / 3 + 3);
+
+    for chunk in bytes.chunks(3) {
+        let value = chunk.iter().enumerate().fold(0u32, |value, (index, byte)| value | (*byte as u32) << (16 - 8 * index));
+        for index in 0..=chunk.len() {
+            encoded.push(BASE64_CHARS[(value >> (18 - 6 * index) & 0x3F) as usize] as char);
+        }
+    }
+
+    encoded
+}
+
+fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 3 / 4);
+
+    for chunk in encoded.as_bytes().chunks(4) {
+        if chunk.len() < 2 {
+            return None;
+        }
+        let mut value = 0u32;
+        for (index, char) in chunk.iter().enumerate() {
+            let digit = BASE64_CHARS.iter().position(|c| c == char)? as u32;
+            value |= digit << (18 - 6 * index);
+        }
+        for index in 0..chunk.len() - 1 {
+            bytes.push((value >> (16 - 8 * index)) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ghost() -> GhostStroke {
+        GhostStroke {
+            samples: vec![(0.0, 0.0, 0.0), (128.0, 145.5, 120.0), (256.0, 291.0, 250.0)],
+            score: 87,
+        }
+    }
+
+    #[test]
+    fn round_trip_keeps_score_and_samples() {
+        let original = ghost();
+        let decoded = GhostStroke::decode(&original.encode()).unwrap();
+
+        assert_eq!(decoded.score, 87);
+        assert_eq!(decoded.samples.len(), original.samples.len());
+        for (decoded, original) in decoded.samples.iter().zip(&original.samples) {
+            // 좌표는 255단계, 시간은 10ms 단위로 저장
+            assert!((decoded.0 - original.0).abs() <= VIEW_BOX_WIDTH / 255.0);
+            assert!((decoded.1 - original.1).abs() <= VIEW_BOX_HEIGHT / 255.0);
+            assert!((decoded.2 - original.2).abs() <= TIME_UNIT);
+        }
+    }
+
+    #[test]
+    fn encode_clamps_score() {
+        let ghost = GhostStroke { score: 250, ..ghost() };
+        assert_eq!(GhostStroke::decode(&ghost.encode()).unwrap().score, 100);
+    }
+
+    #[test]
+    fn decode_rejects_unknown_version() {
+        let code = base64_encode(&[FORMAT_VERSION + 1, 50, 10, 10, 1]);
+        assert_eq!(GhostStroke::decode(&code), None);
+    }
+
+    #[test]
+    fn decode_rejects_score_above_100() {
+        let code = base64_encode(&[FORMAT_VERSION, 101, 10, 10, 1]);
+        assert_eq!(GhostStroke::decode(&code), None);
+    }
+
+    #[test]
+    fn decode_rejects_partial_sample() {
+        let code = base64_encode(&[FORMAT_VERSION, 50, 10, 10]);
+        assert_eq!(GhostStroke::decode(&code), None);
+    }
+
+    #[test]
+    fn decode_rejects_malformed_input() {
+        assert_eq!(GhostStroke::decode(""), None);
+        assert_eq!(GhostStroke::decode("A"), None);
+        assert_eq!(GhostStroke::decode("!!!!"), None);
+    }
+}
@@ -1,9 +1,24 @@
+mod achievement;
 mod app;
+mod assist;
+mod campaign;
 mod component;
+mod decoration;
+mod device;
+mod duel;
 mod func;
+mod ghost;
 mod lottie;
+mod palm;
+mod party;
+mod pattern;
 mod upload;
 mod share;
+mod stats;
+mod storage;
+mod stroke;
+mod survival;
+mod tournament;
 
 use app::TreeDrawingChallenge;
 
@@ -0,0 +1,32 @@
+use crate::decoration::point_in_polygon;
+use crate::func::distance_to_pattern;
+use crate::stroke::PointerKind;
+use web_sys::PointerEvent;
+
+// 손가락 접촉으로 보는 최대 크기 (CSS 픽셀, Touch.radiusX/radiusY의 두 배인 width/height 기준)
+const MAX_FINGER_CONTACT: i32 = 64;
+// 트리 밖이라도 선을 시작할 수 있는 가이드와의 거리 (viewBox 좌표)
+const START_MARGIN: f64 = 32.0;
+
+/// 손바닥으로 보이는 넓은 터치인지
+///
+/// 접촉 크기를 알려주지 않는 브라우저는 1을 보내므로 항상 손가락으로 봅니다.
+pub fn is_palm_contact(event: &PointerEvent) -> bool {
+    is_touch(event) && event.width().max(event.height()) > MAX_FINGER_CONTACT
+}
+
+/// 이 포인터로 선을 시작할 수 있는지 (손바닥 거부)
+///
+/// 마우스와 펜은 항상 허용하고, 터치는 손가락 크기이면서 트리 영역 안에서 시작해야 합니다.
+/// 화면에 손바닥을 먼저 올려도 트리 안에서 처음 시작한 손가락 하나만 추적하게 됩니다.
+pub fn accepts_start(event: &PointerEvent, point: (f64, f64), pattern: &[(f64, f64)]) -> bool {
+    !is_touch(event) || (!is_palm_contact(event) && starts_in_tree(point, pattern))
+}
+
+fn is_touch(event: &PointerEvent) -> bool {
+    PointerKind::from_pointer_type(&event.pointer_type()) == PointerKind::Touch
+}
+
+fn starts_in_tree(point: (f64, f64), pattern: &[(f64, f64)]) -> bool {
+    point_in_polygon(point, pattern) || distance_to_pattern(point, pattern).0 <= START_MARGIN
+}
@@ -0,0 +1,68 @@
+use crate::func::ScoreBreakdown;
+use std::cmp::Ordering;
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 8;
+
+/// 한 기기를 돌려가며 플레이하는 파티 모드 참가자
+#[derive(Clone, PartialEq)]
+pub struct PartyPlayer {
+    pub name: String,
+    pub result: Option<ScoreBreakdown>,
+}
+
+/// 파티 모드 세션 (참가자와 현재 차례)
+#[derive(Clone, PartialEq)]
+pub struct PartySession {
+    pub players: Vec<PartyPlayer>,
+    pub current: usize,
+}
+
+impl PartySession {
+    pub fn new(names: Vec<String>) -> Self {
+        Self {
+            players: names
+                .into_iter()
+                .map(|name| PartyPlayer { name, result: None })
+                .collect(),
+            current: 0,
+        }
+    }
+
+    pub fn current_player(&self) -> Option<&PartyPlayer> {
+        self.players.get(self.current)
+    }
+
+    /// 현재 차례 플레이어의 결과 기록
+    pub fn record(&mut self, breakdown: ScoreBreakdown) {
+        if let Some(player) = self.players.get_mut(self.current) {
+            player.result = Some(breakdown);
+        }
+    }
+
+    /// 다음 차례로 넘기고, 모두 플레이했으면 `false` 반환
+    pub fn advance(&mut self) -> bool {
+        self.current += 1;
+        self.current < self.players.len()
+    }
+
+    /// 순위표 (점수 높은 순, 동점이면 빨리 그린 순, 그다음 정밀한 순)
+    pub fn standings(&self) -> Vec<&PartyPlayer> {
+        let mut standings: Vec<&PartyPlayer> = self.players.iter().collect();
+        standings.sort_by(|a, b| match (&a.result, &b.result) {
+            (Some(a), Some(b)) => compare_results(a, b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        standings
+    }
+}
+
+/// 두 결과 비교 (더 좋은 결과가 앞으로)
+pub fn compare_results(a: &ScoreBreakdown, b: &ScoreBreakdown) -> Ordering {
+    b.score
+        .cmp(&a.score)
+        .then(a.time_used.total_cmp(&b.time_used))
+        .then(a.mean_deviation.total_cmp(&b.mean_deviation))
+}
@@ -0,0 +1,222 @@
+// 패턴 좌표계 (SVG viewBox) 크기
+pub const VIEW_BOX_WIDTH: f64 = 256.0;
+pub const VIEW_BOX_HEIGHT: f64 = 291.0;
+
+/// 그리기 패턴 (채점용 좌표 + 화면에 그려지는 외곽선)
+#[derive(Clone, PartialEq)]
+pub struct Pattern {
+    pub points: Vec<(f64, f64)>, // 패턴을 하나의 연속된 좌표로 저장 (첫 점이 시작점)
+    pub outline: Outline,        // 가이드로 표시할 SVG path 데이터
+}
+
+/// 가이드 외곽선 (viewBox 0 0 256 291 기준)
+#[derive(Clone, PartialEq)]
+pub enum Outline {
+    Filled(&'static str), // 채워진 도형으로 그린 외곽선 (트리 디자인)
+    Stroked(String),      // 좌표를 선으로 이은 외곽선 (절차적 생성 패턴)
+}
+
+impl Pattern {
+    /// 크리스마스 트리 패턴
+    pub fn tree() -> Self {
+        let points = vec![
+            (130.0, 0.0),   // 트리 꼭대기
+            (120.0, 16.0),
+            (110.0, 32.0),
+            (100.0, 48.0),
+            (90.0, 64.0),
+            (80.0, 80.0),
+            (70.0, 96.0),
+            (60.0, 112.0),
+            (50.0, 128.0),
+            (40.0, 144.0),
+            (30.0, 160.0),  // 오른쪽
+            (40.0, 160.0),
+            (50.0, 160.0),
+            (60.0, 160.0),
+            (70.0, 160.0),
+            (70.0, 160.0),
+            (63.0, 173.0),
+            (56.0, 186.0),
+            (49.0, 199.0),
+            (42.0, 212.0),
+            (35.0, 225.0),
+            (28.0, 238.0),
+            (21.0, 251.0),
+            (14.0, 264.0),
+            (7.0, 277.0),
+            (0.0, 290.0),  // 밑바닥
+            (25.0, 290.0),
+            (50.0, 290.0),
+            (75.0, 290.0),
+            (100.0, 290.0),
+            (125.0, 290.0),
+            (150.0, 290.0),
+            (175.0, 290.0),
+            (200.0, 290.0),
+            (225.0, 290.0),
+            (250.0, 290.0),
+            (243.0, 277.0),
+            (236.0, 264.0),
+            (229.0, 251.0),
+            (222.0, 238.0),
+            (215.0, 225.0),
+            (208.0, 212.0),
+            (201.0, 199.0),
+            (194.0, 186.0),
+            (187.0, 173.0),
+            (180.0, 160.0), // 왼쪽
+            (190.0, 160.0),
+            (200.0, 160.0),
+            (210.0, 160.0),
+            (220.0, 160.0), // 트리 꼭대기
+            (211.0, 144.0),
+            (202.0, 128.0),
+            (193.0, 112.0),
+            (184.0, 96.0),
+            (175.0, 80.0),
+            (166.0, 64.0),
+            (157.0, 48.0),
+            (148.0, 32.0),
+            (139.0, 16.0),
+            (130.0, 0.0),
+        ];
+
+        Self {
+            points,
+            outline: Outline::Filled(TREE_OUTLINE),
+        }
+    }
+
+    /// 여러 층으로 된 트리를 `seed`에 따라 무작위로 생성
+    ///
+    /// 같은 `seed`는 항상 같은 모양을 만듭니다.
+    pub fn procedural_tree(seed: u32) -> Self {
+        let mut random = Lcg(seed);
+        let tiers = 2 + (random.next() * 3.0) as usize; // 2 ~ 4층
+        let tier_height = 290.0 / tiers as f64;
+        let center = 128.0;
+
+        // 층별 바깥 반폭과 다음 층이 시작되는 안쪽 반폭
+        let outer: Vec<f64> = (0..tiers)
+            .map(|tier| {
+                let ratio = (tier + 1) as f64 / tiers as f64;
+                (40.0 + 85.0 * ratio * (0.85 + 0.15 * random.next())).min(125.0)
+            })
+            .collect();
+        let inner: Vec<f64> = outer.iter().map(|width| width * (0.5 + 0.2 * random.next())).collect();
+
+        let mut corners = vec![(center, 0.0)];
+        for tier in 0..tiers {
+            let y = tier_height * (tier + 1) as f64;
+            corners.push((center - outer[tier], y));
+            if tier + 1 < tiers {
+                corners.push((center - inner[tier], y));
+            }
+        }
+        for tier in (0..tiers).rev() {
+            let y = tier_height * (tier + 1) as f64;
+            if tier + 1 < tiers {
+                corners.push((center + inner[tier], y));
+            }
+            corners.push((center + outer[tier], y));
+        }
+        corners.push((center, 0.0));
+
+        // 원래 트리와 비슷한 간격으로 점 채우기
+        let mut points = vec![corners[0]];
+        for segment in corners.windows(2) {
+            let steps = (distance(segment[0], segment[1]) / 16.0).ceil().max(1.0) as usize;
+            for step in 1..=steps {
+                let ratio = step as f64 / steps as f64;
+                points.push((
+                    segment[0].0 + (segment[1].0 - segment[0].0) * ratio,
+                    segment[0].1 + (segment[1].1 - segment[0].1) * ratio,
+                ));
+            }
+        }
+
+        let outline = corners
+            .iter()
+            .enumerate()
+            .map(|(index, (x, y))| format!("{}{:.1} {:.1}", if index == 0 { "M" } else { "L" }, x, y))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Self {
+            points,
+            outline: Outline::Stroked(outline),
+        }
+    }
+
+    /// 패턴의 시작점 (첫 번째 꼭짓점)
+    pub fn start_point(&self) -> Option<(f64, f64)> {
+        self.points.first().copied()
+    }
+
+    /// 패턴 전체 길이
+    pub fn length(&self) -> f64 {
+        self.points
+            .windows(2)
+            .map(|segment| distance(segment[0], segment[1]))
+            .sum()
+    }
+
+    /// 시작점부터 `progress`(0.0 ~ 1.0) 비율만큼 따라간 경로
+    pub fn trace(&self, progress: f64) -> Vec<(f64, f64)> {
+        let mut remaining = self.length() * progress.clamp(0.0, 1.0);
+        let mut traced: Vec<(f64, f64)> = self.points.iter().take(1).copied().collect();
+
+        for segment in self.points.windows(2) {
+            let length = distance(segment[0], segment[1]);
+            if remaining >= length {
+                traced.push(segment[1]);
+                remaining -= length;
+            } else {
+                let ratio = remaining / length;
+                traced.push((
+                    segment[0].0 + (segment[1].0 - segment[0].0) * ratio,
+                    segment[0].1 + (segment[1].1 - segment[0].1) * ratio,
+                ));
+                break;
+            }
+        }
+
+        traced
+    }
+
+    /// 진행 방향 화살표 위치와 각도(도) 목록
+    ///
+    /// `step` 개의 선분마다 선분 중앙에 하나씩 배치하며, 길이가 0인 선분은 건너뜁니다.
+    pub fn direction_arrows(&self, step: usize) -> Vec<(f64, f64, f64)> {
+        self.points
+            .windows(2)
+            .filter(|segment| segment[0] != segment[1])
+            .enumerate()
+            .filter(|(index, _)| index % step.max(1) == step.max(1) / 2)
+            .map(|(_, segment)| {
+                let (x1, y1) = segment[0];
+                let (x2, y2) = segment[1];
+                let angle = (y2 - y1).atan2(x2 - x1).to_degrees();
+                ((x1 + x2) / 2.0, (y1 + y2) / 2.0, angle)
+            })
+            .collect()
+    }
+}
+
+// 절차적 패턴 생성용 난수 (0.0 ~ 1.0)
+struct Lcg(u32);
+
+impl Lcg {
+    fn next(&mut self) -> f64 {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (self.0 >> 8) as f64 / (1u32 << 24) as f64
+    }
+}
+
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+// 트리 외곽선 SVG path (viewBox 0 0 256 291)
+const TREE_OUTLINE: &str = "M121.15 8.50157L117.724 6.43576V6.43576L121.15 8.50157ZM134.851 8.50158L131.425 10.5674V10.5674L134.851 8.50158ZM35.8845 149.877L39.3097 151.942L35.8845 149.877ZM68.6952 162.008L72.1781 163.975C72.8776 162.737 72.8668 161.22 72.1498 159.992C71.4329 158.763 70.1175 158.008 68.6952 158.008V162.008ZM5.04591 274.704L1.56301 272.737H1.56301L5.04591 274.704ZM250.954 274.704L254.437 272.737L250.954 274.704ZM187.305 162.008V158.008C185.882 158.008 184.567 158.763 183.85 159.992C183.133 161.22 183.122 162.737 183.822 163.975L187.305 162.008ZM220.116 149.877L216.69 151.942V151.942L220.116 149.877ZM124.575 10.5674C126.13 7.98848 129.87 7.98849 131.425 10.5674L138.276 6.43576C133.61 -1.30098 122.39 -1.30097 117.724 6.43576L124.575 10.5674ZM39.3097 151.942L124.575 10.5674L117.724 6.43576L32.4592 147.811L39.3097 151.942ZM42.735 158.008C39.6217 158.008 37.7019 154.608 39.3097 151.942L32.4592 147.811C27.6356 155.809 33.3952 166.008 42.735 166.008V158.008ZM68.6952 158.008H42.735V166.008H68.6952V158.008ZM8.5288 276.671L72.1781 163.975L65.2123 160.041L1.56301 272.737L8.5288 276.671ZM12.0117 282.638C8.94932 282.638 7.0228 279.338 8.5288 276.671L1.56301 272.737C-2.95499 280.737 2.82455 290.638 12.0117 290.638V282.638ZM243.988 282.638H12.0117V290.638H243.988V282.638ZM247.471 276.671C248.977 279.338 247.051 282.638 243.988 282.638V290.638C253.175 290.638 258.955 280.737 254.437 272.737L247.471 276.671ZM183.822 163.975L247.471 276.671L254.437 272.737L190.788 160.041L183.822 163.975ZM213.265 158.008H187.305V166.008H213.265V158.008ZM216.69 151.942C218.298 154.608 216.378 158.008 213.265 158.008V166.008C222.605 166.008 228.364 155.809 223.541 147.811L216.69 151.942ZM131.425 10.5674L216.69 151.942L223.541 147.811L138.276 6.43576L131.425 10.5674Z";
@@ -4,15 +4,30 @@ use web_sys::{window, console};
 use gloo::dialogs::alert;
 use wasm_bindgen::prelude::*;
 
-pub fn share_to_twitter(image_url: &str, myscore: &str) {
+const SITE_URL: &str = "https://drawtree.netlify.app";
+
+/// 공유할 게임 링크 (고스트 코드가 있으면 도전장 링크)
+pub fn challenge_url(ghost_code: Option<&str>) -> String {
+    match ghost_code {
+        Some(code) => format!("{}/?ghost={}", SITE_URL, code),
+        None => SITE_URL.to_string(),
+    }
+}
+
+/// 공유 문구 (제한 시간 모드 포함)
+fn score_message(myscore: &str, time_label: &str) -> String {
+    format!("[{}] 내 점수는 {}점! 너도 도전해볼래?", time_label, myscore)
+}
+
+pub fn share_to_twitter(image_url: &str, link_url: &str, myscore: &str, time_label: &str) {
 
     // 트윗 텍스트와 이미지 URL 인코딩
     let image_url_encoded = encode(image_url);
-    let score_message = format!("내 점수는 {}점! 너도 도전해볼래?", myscore);
+    let score_message = score_message(myscore, time_label);
     let tweet_text = format!(
         "{}%0A{}%0A{}",
         encode("🎄트리 그리기 챌린지🎄"),
-        encode("https://drawtree.netlify.app"),
+        encode(link_url),
         encode(&score_message)
     );
 
@@ -32,15 +47,15 @@ pub fn share_to_twitter(image_url: &str, myscore: &str) {
     }
 }
 
-pub fn share_to_facebook(image_url: &str, myscore: &str) {
+pub fn share_to_facebook(image_url: &str, link_url: &str, myscore: &str, time_label: &str) {
 
     // URL 인코딩
     let image_url_encoded = encode(image_url);
-    let score_message = format!("내 점수는 {}점! 너도 도전해볼래?", myscore);
+    let score_message = score_message(myscore, time_label);
     let text = format!(
         "{}%0A{}%0A{}",
         encode("🎄트리 그리기 챌린지🎄"),
-        encode("https://drawtree.netlify.app"),
+        encode(link_url),
         encode(&score_message),
     );
     // facebook intent URL 생성
@@ -75,7 +90,7 @@ extern "C" {
     pub fn shareKakao(options: &str);
 }
 
-pub fn share_to_kakao(image_url: &str, myscore: &str) {
+pub fn share_to_kakao(image_url: &str, link_url: &str, myscore: &str, time_label: &str) {
 
     let app_key = "KEY";
     initKakao(app_key);
@@ -85,19 +100,19 @@ pub fn share_to_kakao(image_url: &str, myscore: &str) {
         "objectType": "feed",
         "content": {
             "title": "🎄트리 그리기 챌린지🎄",
-            "description": format!("내 점수는 {}점! 너도 도전해볼래?", myscore),
+            "description": score_message(myscore, time_label),
             "imageUrl": image_url,
             "link": {
-                "mobileWebUrl": "https://drawtree.netlify.app",
-                "webUrl": "https://drawtree.netlify.app"
+                "mobileWebUrl": link_url,
+                "webUrl": link_url
             }
         },
         "buttons": [
             {
                 "title": "도전하러 가기",
                 "link": {
-                    "mobileWebUrl": "https://drawtree.netlify.app",
-                    "webUrl": "https://drawtree.netlify.app"
+                    "mobileWebUrl": link_url,
+                    "webUrl": link_url
                 }
             }
         ]
@@ -107,14 +122,15 @@ pub fn share_to_kakao(image_url: &str, myscore: &str) {
     shareKakao(&options);
 }
 
-pub fn copy_to_link(image_url: &str, myscore: &str) {
+pub fn copy_to_link(image_url: &str, link_url: &str, myscore: &str, time_label: &str) {
     // 클립보드 API 사용
     if let Some(window) = window() {
         let navigator = window.navigator();
         let clipboard = navigator.clipboard();
         let text = format!(
-            "🎄트리 그리기 챌린지🎄\nhttps://drawtree.netlify.app\n내 점수는 {}점! 너도 도전해볼래?\n{}",
-            myscore,
+            "🎄트리 그리기 챌린지🎄\n{}\n{}\n{}",
+            link_url,
+            score_message(myscore, time_label),
             image_url);
 
         let promise = clipboard.write_text(&text); // 클립보드에 텍스트 쓰기
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 하루 동안의 기록
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DayRecord {
+    pub best_score: u32,
+    pub rounds: u32,
+}
+
+/// 날짜별 플레이 기록 (로컬 저장, 키는 "YYYY-MM-DD")
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DailyRecord {
+    pub days: BTreeMap<String, DayRecord>,
+}
+
+impl DailyRecord {
+    /// 라운드 하나를 끝낸 기록 추가
+    pub fn record(&mut self, date: String, score: u32) {
+        let day = self.days.entry(date).or_default();
+        day.best_score = day.best_score.max(score);
+        day.rounds += 1;
+    }
+
+    pub fn get(&self, date: &str) -> Option<&DayRecord> {
+        self.days.get(date)
+    }
+
+    /// 현재 연속 플레이 일수 (오늘 아직 안 했으면 어제까지의 연속 기록)
+    pub fn current_streak(&self, today: &str) -> u32 {
+        let Some(today) = day_number(today) else {
+            return 0;
+        };
+        let played = |day: i64| self.days.contains_key(&format_day(day));
+        let mut day = if played(today) { today } else { today - 1 };
+
+        let mut streak = 0;
+        while played(day) {
+            streak += 1;
+            day -= 1;
+        }
+        streak
+    }
+
+    /// 가장 길었던 연속 플레이 일수
+    pub fn best_streak(&self) -> u32 {
+        let mut best = 0;
+        let mut streak = 0;
+        let mut previous = None;
+
+        // BTreeMap은 날짜 순으로 정렬되어 있음
+        for day in self.days.keys().filter_map(|date| day_number(date)) {
+            streak = if previous == Some(day - 1) { streak + 1 } else { 1 };
+            best = best.max(streak);
+            previous = Some(day);
+        }
+        best
+    }
+}
+
+/// 달력 한 달 (연, 월)의 날짜 목록과 첫 날의 요일 (일요일 = 0)
+pub fn month_days(year: i64, month: u32) -> (u32, Vec<String>) {
+    let first = days_from_civil(year, month, 1);
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    let length = days_from_civil(next_year, next_month, 1) - first;
+
+    let weekday = (first + 4).rem_euclid(7) as u32; // 1970-01-01은 목요일
+    let dates = (0..length).map(|offset| format_day(first + offset)).collect();
+    (weekday, dates)
+}
+
+/// "YYYY-MM-DD"에서 연, 월 읽기
+pub fn year_month(date: &str) -> Option<(i64, u32)> {
+    let mut parts = date.split('-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    Some((year, month))
+}
+
+// "YYYY-MM-DD"를 1970-01-01부터의 일수로 변환
+fn day_number(date: &str) -> Option<i64> {
+    let mut parts = date.split('-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    Some(days_from_civil(year, month, day))
+}
+
+fn format_day(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// 그레고리력 날짜 <-> 일수 변환 (Howard Hinnant 알고리즘)
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
@@ -0,0 +1,90 @@
+use crate::achievement::AchievementProgress;
+use crate::assist::AssistSettings;
+use crate::campaign::CampaignProgress;
+use crate::stats::DailyRecord;
+use crate::tournament::Bracket;
+use gloo::storage::{LocalStorage, Storage};
+
+const TUTORIAL_SEEN_KEY: &str = "tree-drawing-challenge:tutorial-seen";
+const CAMPAIGN_PROGRESS_KEY: &str = "tree-drawing-challenge:campaign-progress";
+const TOURNAMENT_KEY: &str = "tree-drawing-challenge:tournament";
+const ACHIEVEMENTS_KEY: &str = "tree-drawing-challenge:achievements";
+const DAILY_RECORD_KEY: &str = "tree-drawing-challenge:daily-record";
+const ASSIST_SETTINGS_KEY: &str = "tree-drawing-challenge:assist-settings";
+
+/// 시범 영상을 이미 본 적이 있는지 확인
+pub fn is_tutorial_seen() -> bool {
+    LocalStorage::get::<bool>(TUTORIAL_SEEN_KEY).unwrap_or(false)
+}
+
+/// 시범 영상 시청 여부 저장
+pub fn set_tutorial_seen() {
+    if let Err(err) = LocalStorage::set(TUTORIAL_SEEN_KEY, true) {
+        web_sys::console::error_1(&format!("Failed to save tutorial state: {}", err).into());
+    }
+}
+
+/// 캠페인 진행 상황 불러오기
+pub fn load_campaign_progress() -> CampaignProgress {
+    LocalStorage::get(CAMPAIGN_PROGRESS_KEY).unwrap_or_default()
+}
+
+/// 캠페인 진행 상황 저장
+pub fn save_campaign_progress(progress: &CampaignProgress) {
+    if let Err(err) = LocalStorage::set(CAMPAIGN_PROGRESS_KEY, progress) {
+        web_sys::console::error_1(&format!("Failed to save campaign progress: {}", err).into());
+    }
+}
+
+/// 진행 중인 토너먼트 대진표 불러오기
+pub fn load_tournament() -> Option<Bracket> {
+    LocalStorage::get(TOURNAMENT_KEY).ok()
+}
+
+/// 토너먼트 대진표 저장 (새로고침해도 이어서 진행)
+pub fn save_tournament(bracket: &Bracket) {
+    if let Err(err) = LocalStorage::set(TOURNAMENT_KEY, bracket) {
+        web_sys::console::error_1(&format!("Failed to save tournament: {}", err).into());
+    }
+}
+
+/// 토너먼트 대진표 삭제
+pub fn clear_tournament() {
+    LocalStorage::delete(TOURNAMENT_KEY);
+}
+
+/// 업적 진행 상황 불러오기
+pub fn load_achievements() -> AchievementProgress {
+    LocalStorage::get(ACHIEVEMENTS_KEY).unwrap_or_default()
+}
+
+/// 업적 진행 상황 저장
+pub fn save_achievements(progress: &AchievementProgress) {
+    if let Err(err) = LocalStorage::set(ACHIEVEMENTS_KEY, progress) {
+        web_sys::console::error_1(&format!("Failed to save achievements: {}", err).into());
+    }
+}
+
+/// 날짜별 플레이 기록 불러오기
+pub fn load_daily_record() -> DailyRecord {
+    LocalStorage::get(DAILY_RECORD_KEY).unwrap_or_default()
+}
+
+/// 날짜별 플레이 기록 저장
+pub fn save_daily_record(record: &DailyRecord) {
+    if let Err(err) = LocalStorage::set(DAILY_RECORD_KEY, record) {
+        web_sys::console::error_1(&format!("Failed to save daily record: {}", err).into());
+    }
+}
+
+/// 보조 모드 설정 불러오기
+pub fn load_assist_settings() -> AssistSettings {
+    LocalStorage::get(ASSIST_SETTINGS_KEY).unwrap_or_default()
+}
+
+/// 보조 모드 설정 저장
+pub fn save_assist_settings(settings: &AssistSettings) {
+    if let Err(err) = LocalStorage::set(ASSIST_SETTINGS_KEY, settings) {
+        web_sys::console::error_1(&format!("Failed to save assist settings: {}", err).into());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::pattern::{VIEW_BOX_HEIGHT, VIEW_BOX_WIDTH};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::PointerEvent;
+
+// 패턴 좌표계 밖으로 허용하는 여유 (가장자리 선을 살짝 넘어 그리는 경우)
+pub const PLAY_AREA_MARGIN: f64 = 24.0;
+
+/// 입력 장치 종류 (PointerEvent.pointerType)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum PointerKind {
+    #[default]
+    Touch,
+    Mouse,
+    Pen,
+    Keyboard, // 보조 모드 방향키/스위치 입력
+}
+
+impl PointerKind {
+    pub fn from_pointer_type(pointer_type: &str) -> Self {
+        match pointer_type {
+            "mouse" => PointerKind::Mouse,
+            "pen" => PointerKind::Pen,
+            _ => PointerKind::Touch,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PointerKind::Touch => "터치",
+            PointerKind::Mouse => "마우스",
+            PointerKind::Pen => "펜",
+            PointerKind::Keyboard => "키보드",
+        }
+    }
+}
+
+/// 그린 선의 좌표 하나와 입력 당시 상태
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StrokeSample {
+    pub x: f64, // viewBox 좌표
+    pub y: f64,
+    pub t: f64,        // 이벤트 timeStamp (밀리초)
+    pub pressure: f64, // 0.0 ~ 1.0 (압력을 지원하지 않는 장치는 누르는 동안 0.5)
+    pub tilt_x: i32,   // 펜 기울기 (도)
+    pub tilt_y: i32,
+    pub pointer_type: PointerKind,
+    pub out_of_bounds: bool, // 그리는 영역 밖 좌표 (영역 경계로 잘라서 저장, 채점 제외)
+}
+
+impl StrokeSample {
+    /// 포인터 이벤트와 변환된 좌표로 샘플 생성
+    ///
+    /// 손가락이 보드나 화면 밖으로 나가도 캡처된 포인터의 좌표는 계속 들어오므로,
+    /// 그리는 영역을 벗어난 좌표는 경계로 잘라내고 표시해 둡니다.
+    pub fn from_event(event: &PointerEvent, (x, y): (f64, f64)) -> Self {
+        let (clamped_x, clamped_y) = (
+            x.clamp(-PLAY_AREA_MARGIN, VIEW_BOX_WIDTH + PLAY_AREA_MARGIN),
+            y.clamp(-PLAY_AREA_MARGIN, VIEW_BOX_HEIGHT + PLAY_AREA_MARGIN),
+        );
+
+        Self {
+            x: clamped_x,
+            y: clamped_y,
+            t: event.time_stamp(),
+            pressure: event.pressure() as f64,
+            tilt_x: event.tilt_x(),
+            tilt_y: event.tilt_y(),
+            pointer_type: PointerKind::from_pointer_type(&event.pointer_type()),
+            out_of_bounds: (clamped_x, clamped_y) != (x, y),
+        }
+    }
+
+    /// 보조 모드에서 방향키로 움직인 커서 위치로 샘플 생성 (커서는 항상 영역 안)
+    pub fn from_key((x, y): (f64, f64), t: f64) -> Self {
+        Self {
+            x,
+            y,
+            t,
+            pressure: 0.5,
+            tilt_x: 0,
+            tilt_y: 0,
+            pointer_type: PointerKind::Keyboard,
+            out_of_bounds: false,
+        }
+    }
+
+    pub fn point(&self) -> (f64, f64) {
+        (self.x, self.y)
+    }
+
+    /// 필압에 따른 선 굵기 배율 (펜이 아니면 항상 1.0)
+    pub fn width_scale(&self) -> f64 {
+        match self.pointer_type {
+            PointerKind::Pen => 0.4 + 1.2 * self.pressure.clamp(0.0, 1.0),
+            _ => 1.0,
+        }
+    }
+}
+
+/// 샘플 목록에서 좌표만 추출
+pub fn points(samples: &[StrokeSample]) -> Vec<(f64, f64)> {
+    samples.iter().map(StrokeSample::point).collect()
+}
+
+/// 그리는 영역 밖에 머문 시간 (밀리초)
+///
+/// 영역 밖 샘플부터 다음 샘플까지의 간격을 모두 더합니다.
+pub fn out_of_bounds_time(samples: &[StrokeSample]) -> f64 {
+    samples
+        .windows(2)
+        .filter(|pair| pair[0].out_of_bounds)
+        .map(|pair| (pair[1].t - pair[0].t).max(0.0))
+        .sum()
+}
+
+/// 그리는 중인 경로를 보관하는 공유 버퍼
+///
+/// 그리기 보드가 좌표를 추가하고, 라운드가 끝나면 앱이 읽어갑니다.
+/// 시간 초과로 보드가 사라져도 그리던 경로를 잃지 않습니다.
+#[derive(Clone, Default)]
+pub struct StrokeBuffer(Rc<RefCell<Vec<StrokeSample>>>);
+
+impl StrokeBuffer {
+    pub fn push(&self, sample: StrokeSample) {
+        self.0.borrow_mut().push(sample);
+    }
+
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+
+    /// 현재까지 그린 샘플 복사본
+    pub fn snapshot(&self) -> Vec<StrokeSample> {
+        self.0.borrow().clone()
+    }
+
+    /// `start`번째 샘플부터 새로 추가된 좌표 (화면에 이어 그릴 부분)
+    pub fn points_from(&self, start: usize) -> Vec<(f64, f64)> {
+        self.0.borrow().get(start..).map(points).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+// 같은 버퍼를 가리키는지로 비교 (내용 비교로 인한 불필요한 렌더링 방지)
+impl PartialEq for StrokeBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::pattern::Pattern;
+
+/// 다음 라운드로 넘어가기 위한 최소 점수
+pub const PASS_SCORE: u32 = 70;
+
+/// 서바이벌 모드 진행 상황
+#[derive(Clone, PartialEq)]
+pub struct SurvivalRun {
+    pub round: u32,       // 현재 라운드 (0부터 시작)
+    pub total_score: u32, // 통과한 라운드 점수 합계
+    pub seed: u32,        // 절차적 패턴 생성용 시드
+}
+
+/// 라운드별 규칙
+pub struct RoundRules {
+    pub duration: f64,
+    pub tolerance: f64,
+    pub pattern: Pattern,
+}
+
+impl SurvivalRun {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            round: 0,
+            total_score: 0,
+            seed,
+        }
+    }
+
+    /// 통과한 라운드 수
+    pub fn rounds_survived(&self) -> u32 {
+        self.round
+    }
+
+    /// 라운드를 통과하면 점수를 누적하고 다음 라운드로 진행
+    pub fn advance(&mut self, score: u32) {
+        self.total_score += score;
+        self.round += 1;
+    }
+
+    /// 현재 라운드 규칙
+    ///
+    /// 라운드마다 시간과 허용 거리가 줄어들고, 세 라운드마다 새로운 모양이 등장합니다.
+    pub fn rules(&self) -> RoundRules {
+        let round = self.round as f64;
+        let shape = self.round / 3;
+
+        RoundRules {
+            duration: (5000.0 - 300.0 * round).max(2000.0),
+            tolerance: (12.0 - 0.5 * round).max(6.0),
+            pattern: if shape == 0 {
+                Pattern::tree()
+            } else {
+                Pattern::procedural_tree(self.seed.wrapping_add(shape))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_at(round: u32) -> SurvivalRun {
+        SurvivalRun { round, ..SurvivalRun::new(7) }
+    }
+
+    #[test]
+    fn first_round_uses_base_rules() {
+        let rules = run_at(0).rules();
+
+        assert_eq!(rules.duration, 5000.0);
+        assert_eq!(rules.tolerance, 12.0);
+        assert!(rules.pattern == Pattern::tree());
+    }
+
+    #[test]
+    fn rules_tighten_each_round() {
+        let rules = run_at(4).rules();
+
+        assert_eq!(rules.duration, 3800.0);
+        assert_eq!(rules.tolerance, 10.0);
+    }
+
+    #[test]
+    fn rules_stop_at_minimums() {
+        for round in [12, 30, 1000] {
+            let rules = run_at(round).rules();
+            assert_eq!(rules.duration, 2000.0);
+            assert_eq!(rules.tolerance, 6.0);
+        }
+    }
+
+    #[test]
+    fn new_shape_every_three_rounds() {
+        assert!(run_at(2).rules().pattern == Pattern::tree());
+        assert!(run_at(3).rules().pattern != Pattern::tree());
+        assert!(run_at(3).rules().pattern == run_at(5).rules().pattern);
+    }
+
+    #[test]
+    fn advance_accumulates_score() {
+        let mut run = SurvivalRun::new(7);
+        run.advance(80);
+        run.advance(75);
+
+        assert_eq!(run.rounds_survived(), 2);
+        assert_eq!(run.total_score, 155);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::func::ScoreBreakdown;
+use crate::party::compare_results;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+pub const MAX_ENTRANTS: usize = 16;
+
+/// 토너먼트 한 경기 (두 사람이 같은 패턴을 한 번씩 연달아 그림)
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Match {
+    pub players: [Option<String>; 2],         // 아직 정해지지 않았거나 부전승이면 None
+    pub results: [Option<ScoreBreakdown>; 2],
+    pub winner: Option<usize>,                // 승자 자리 (0 또는 1)
+}
+
+impl Match {
+    /// 다음에 그릴 자리 (두 사람이 모두 정해졌고 아직 승부가 나지 않은 경우)
+    pub fn next_turn(&self) -> Option<usize> {
+        if self.winner.is_some() || self.players.iter().any(Option::is_none) {
+            return None;
+        }
+        self.results.iter().position(Option::is_none)
+    }
+
+    pub fn winner_name(&self) -> Option<&str> {
+        self.winner.and_then(|slot| self.players[slot].as_deref())
+    }
+}
+
+/// 싱글 엘리미네이션 대진표
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bracket {
+    pub rounds: Vec<Vec<Match>>, // 첫 라운드부터 결승까지
+}
+
+impl Bracket {
+    /// 입력 순서를 시드로 대진표 생성 (인원이 모자라면 상위 시드가 부전승)
+    ///
+    /// 표준 대진 순서(8강이면 1-8, 4-5, 3-6, 2-7)로 배치해서 1, 2번 시드는 결승에서만 만납니다.
+    pub fn new(names: Vec<String>) -> Self {
+        let size = names.len().clamp(2, MAX_ENTRANTS).next_power_of_two();
+        let mut slots: Vec<Option<String>> = names.into_iter().take(MAX_ENTRANTS).map(Some).collect();
+        slots.resize(size, None);
+
+        let first_round = top_seeds(size)
+            .into_iter()
+            .map(|seed| Match {
+                players: [slots[seed].clone(), slots[size - 1 - seed].clone()],
+                ..Match::default()
+            })
+            .collect();
+
+        let mut rounds = vec![first_round];
+        let mut matches = size / 4;
+        while matches >= 1 {
+            rounds.push(vec![Match::default(); matches]);
+            matches /= 2;
+        }
+
+        let mut bracket = Self { rounds };
+        for index in 0..size / 2 {
+            if let [Some(_), None] = bracket.rounds[0][index].players {
+                bracket.decide(0, index, 0); // 부전승
+            }
+        }
+        bracket
+    }
+
+    /// 지금 진행할 경기 (라운드, 경기 번호)
+    pub fn current_match(&self) -> Option<(usize, usize)> {
+        self.rounds.iter().enumerate().find_map(|(round, matches)| {
+            matches
+                .iter()
+                .position(|game| game.next_turn().is_some())
+                .map(|index| (round, index))
+        })
+    }
+
+    /// 지금 그릴 차례인 참가자 이름
+    pub fn current_player(&self) -> Option<&str> {
+        let (round, index) = self.current_match()?;
+        let game = &self.rounds[round][index];
+        game.players[game.next_turn()?].as_deref()
+    }
+
+    /// 현재 경기에서 몇 번째 차례인지 (0: 첫 번째, 1: 두 번째)
+    pub fn current_turn(&self) -> Option<usize> {
+        let (round, index) = self.current_match()?;
+        self.rounds[round][index].next_turn()
+    }
+
+    /// 현재 차례 결과 기록 (두 사람 모두 그리면 승자 결정)
+    ///
+    /// 점수가 같으면 걸린 시간, 그다음 평균 오차로 가리고, 그래도 같으면 상위 시드가 이깁니다.
+    pub fn record(&mut self, breakdown: ScoreBreakdown) {
+        let Some((round, index)) = self.current_match() else {
+            return;
+        };
+        let game = &mut self.rounds[round][index];
+        let Some(turn) = game.next_turn() else {
+            return;
+        };
+        game.results[turn] = Some(breakdown);
+
+        if let [Some(first), Some(second)] = &game.results {
+            let winner = match compare_results(first, second) {
+                Ordering::Less => 0,
+                Ordering::Greater => 1,
+                Ordering::Equal => {
+                    let [first, second] = game.players.clone().map(|player| player.map(|name| self.seed(&name)));
+                    if second < first { 1 } else { 0 }
+                }
+            };
+            self.decide(round, index, winner);
+        }
+    }
+
+    // 참가자의 시드 (0부터, 입력 순서)
+    fn seed(&self, name: &str) -> usize {
+        let size = self.rounds[0].len() * 2;
+        top_seeds(size)
+            .into_iter()
+            .zip(&self.rounds[0])
+            .find_map(|(seed, game)| match game.players.each_ref().map(|player| player.as_deref() == Some(name)) {
+                [true, _] => Some(seed),
+                [_, true] => Some(size - 1 - seed),
+                _ => None,
+            })
+            .unwrap_or(usize::MAX)
+    }
+
+    /// 우승자 (결승이 끝났을 때)
+    pub fn champion(&self) -> Option<&str> {
+        self.rounds.last()?.first()?.winner_name()
+    }
+
+    /// 라운드 이름 (결승, 준결승, N강)
+    pub fn round_label(&self, round: usize) -> String {
+        match self.rounds.len() - round {
+            1 => "결승".to_string(),
+            2 => "준결승".to_string(),
+            remaining => format!("{}강", 1 << remaining),
+        }
+    }
+
+    // 승자를 정하고 다음 라운드 자리에 올림
+    fn decide(&mut self, round: usize, index: usize, slot: usize) {
+        let game = &mut self.rounds[round][index];
+        game.winner = Some(slot);
+        let winner = game.players[slot].clone();
+
+        if let Some(next) = self.rounds.get_mut(round + 1) {
+            next[index / 2].players[index % 2] = winner;
+        }
+    }
+}
+
+/// 첫 라운드 경기별 상위 시드 (0부터), 표준 대진 순서
+///
+/// 한 단계 작은 대진표의 각 시드를 둘로 나누고, 홀수 번째 경기는 순서를 뒤집어
+/// 상위 시드끼리는 가능한 늦게 만나도록 합니다.
+fn top_seeds(size: usize) -> Vec<usize> {
+    if size <= 2 {
+        return vec![0];
+    }
+    top_seeds(size / 2)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, seed)| {
+            let pair = [seed, size / 2 - 1 - seed];
+            if index % 2 == 0 { pair } else { [pair[1], pair[0]] }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(count: usize) -> Vec<String> {
+        (1..=count).map(|seed| format!("P{}", seed)).collect()
+    }
+
+    fn result(score: u32, time_used: f64, mean_deviation: f64) -> ScoreBreakdown {
+        ScoreBreakdown { score, time_used, mean_deviation, ..ScoreBreakdown::default() }
+    }
+
+    #[test]
+    fn seeds_pair_top_against_bottom() {
+        let bracket = Bracket::new(names(4));
+        let first_round: Vec<_> = bracket.rounds[0].iter().map(|game| game.players.clone()).collect();
+
+        assert_eq!(
+            first_round,
+            vec![
+                [Some("P1".to_string()), Some("P4".to_string())],
+                [Some("P2".to_string()), Some("P3".to_string())],
+            ]
+        );
+        assert_eq!(bracket.rounds.len(), 2);
+    }
+
+    #[test]
+    fn top_seeds_get_byes_when_short() {
+        let bracket = Bracket::new(names(5));
+
+        // 8강 대진표에서 상위 3시드는 부전승으로 다음 라운드에 올라감 (1-8, 4-5, 3-6, 2-7)
+        assert_eq!(bracket.rounds.len(), 3);
+        for index in [0, 2, 3] {
+            assert_eq!(bracket.rounds[0][index].winner, Some(0));
+        }
+        assert_eq!(bracket.rounds[0][1].winner, None);
+        assert_eq!(bracket.rounds[1][0].players, [Some("P1".to_string()), None]);
+        assert_eq!(bracket.rounds[1][1].players, [Some("P3".to_string()), Some("P2".to_string())]);
+        assert_eq!(bracket.current_player(), Some("P4"));
+    }
+
+    #[test]
+    fn standard_order_keeps_top_seeds_apart() {
+        assert_eq!(top_seeds(8), vec![0, 3, 2, 1]);
+        assert_eq!(top_seeds(16), vec![0, 7, 4, 3, 2, 5, 6, 1]);
+
+        // 1, 2번 시드는 서로 다른 절반에 있어 결승에서만 만남
+        for size in [4, 8, 16] {
+            let order = top_seeds(size);
+            let (upper, lower) = order.split_at(order.len() / 2);
+            assert!(upper.contains(&0) && lower.contains(&1));
+        }
+    }
+
+    #[test]
+    fn entrants_are_capped() {
+        let bracket = Bracket::new(names(MAX_ENTRANTS + 4));
+        assert_eq!(bracket.rounds[0].len(), MAX_ENTRANTS / 2);
+    }
+
+    #[test]
+    fn higher_score_wins() {
+        let mut bracket = Bracket::new(names(2));
+        bracket.record(result(70, 3000.0, 5.0));
+        bracket.record(result(80, 4000.0, 6.0));
+
+        assert_eq!(bracket.champion(), Some("P2"));
+    }
+
+    #[test]
+    fn tie_break_uses_time_then_deviation_then_seed() {
+        let mut bracket = Bracket::new(names(2));
+        bracket.record(result(80, 4000.0, 5.0));
+        bracket.record(result(80, 3000.0, 6.0));
+        assert_eq!(bracket.champion(), Some("P2"));
+
+        let mut bracket = Bracket::new(names(2));
+        bracket.record(result(80, 3000.0, 6.0));
+        bracket.record(result(80, 3000.0, 5.0));
+        assert_eq!(bracket.champion(), Some("P2"));
+
+        let mut bracket = Bracket::new(names(2));
+        bracket.record(result(80, 3000.0, 5.0));
+        bracket.record(result(80, 3000.0, 5.0));
+        assert_eq!(bracket.champion(), Some("P1"));
+    }
+
+    #[test]
+    fn full_tie_goes_to_higher_seed_in_second_slot() {
+        // 결승에서 4번 시드가 위 칸, 2번 시드가 아래 칸에 놓임
+        let mut bracket = Bracket::new(names(4));
+        bracket.record(result(60, 3000.0, 5.0)); // P1
+        bracket.record(result(90, 3000.0, 5.0)); // P4
+        bracket.record(result(90, 3000.0, 5.0)); // P2
+        bracket.record(result(60, 3000.0, 5.0)); // P3
+        bracket.record(result(80, 3000.0, 5.0)); // P4
+        bracket.record(result(80, 3000.0, 5.0)); // P2
+        assert_eq!(bracket.champion(), Some("P2"));
+    }
+
+    #[test]
+    fn winners_advance_to_final() {
+        let mut bracket = Bracket::new(names(4));
+        bracket.record(result(90, 3000.0, 5.0)); // P1
+        bracket.record(result(60, 3000.0, 5.0)); // P4
+        bracket.record(result(50, 3000.0, 5.0)); // P2
+        bracket.record(result(70, 3000.0, 5.0)); // P3
+
+        assert_eq!(bracket.rounds[1][0].players, [Some("P1".to_string()), Some("P3".to_string())]);
+        assert_eq!(bracket.round_label(1), "결승");
+        assert_eq!(bracket.champion(), None);
+    }
+}
@@ -1,10 +1,16 @@
+use crate::decoration::{DecoratedTree, DecorationKind};
 use std::cell::RefCell;
 use std::rc::Rc;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
 use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement, window};
 
-pub async fn render_canvas(score: u32) -> Result<String, String> {
+// 공유 이미지에서 트리를 그릴 위치와 배율 (viewBox 좌표 기준)
+const TREE_ORIGIN: (f64, f64) = (272.0, 20.0);
+const TREE_SCALE: f64 = 1.0;
+
+/// 공유 이미지 생성 (꾸민 트리가 있으면 경로와 장식을 함께 그림)
+pub async fn render_canvas(score: u32, tree: Option<DecoratedTree>) -> Result<String, String> {
 
     let document = window()
         .ok_or("Failed to get window")?
@@ -132,6 +138,10 @@ pub async fn render_canvas(score: u32) -> Result<String, String> {
             }
     
             web_sys::console::log_1(&"Text rendered on canvas.".into());
+
+            if let Some(tree) = &tree {
+                draw_decorated_tree(&context_clone, tree);
+            }
             sender.send(Ok(())).unwrap();
         }
     }) as Box<dyn Fn()>);    
@@ -152,6 +162,56 @@ pub async fn render_canvas(score: u32) -> Result<String, String> {
         .map_err(|_| "Failed to convert canvas to data URL".to_string())
 }
 
+/// 사용자가 그린 트리와 장식을 캔버스에 그리기
+fn draw_decorated_tree(context: &CanvasRenderingContext2d, tree: &DecoratedTree) {
+    let to_canvas = |(x, y): (f64, f64)| (TREE_ORIGIN.0 + x * TREE_SCALE, TREE_ORIGIN.1 + y * TREE_SCALE);
+
+    // 트리 선
+    context.begin_path();
+    for (index, point) in tree.path.iter().enumerate() {
+        let (x, y) = to_canvas(*point);
+        if index == 0 {
+            context.move_to(x, y);
+        } else {
+            context.line_to(x, y);
+        }
+    }
+    context.set_stroke_style_str("#72F48F");
+    context.set_line_width(8.0 * TREE_SCALE);
+    context.set_line_join("round");
+    context.set_line_cap("round");
+    context.stroke();
+
+    // 장식
+    for decoration in &tree.decorations {
+        context.begin_path();
+        match decoration.kind {
+            DecorationKind::Topper => {
+                for (index, point) in decoration.star_points().into_iter().enumerate() {
+                    let (x, y) = to_canvas(point);
+                    if index == 0 {
+                        context.move_to(x, y);
+                    } else {
+                        context.line_to(x, y);
+                    }
+                }
+                context.close_path();
+            }
+            kind => {
+                let (x, y) = to_canvas((decoration.x, decoration.y));
+                let _ = context.arc(x, y, kind.radius() * TREE_SCALE, 0.0, std::f64::consts::TAU);
+            }
+        }
+
+        // 전구와 별은 빛나 보이도록 번짐 효과
+        let glows = decoration.kind != DecorationKind::Ornament;
+        context.set_shadow_color(decoration.kind.color());
+        context.set_shadow_blur(if glows { 12.0 } else { 0.0 });
+        context.set_fill_style_str(decoration.kind.color());
+        context.fill();
+    }
+    context.set_shadow_blur(0.0);
+}
 
 pub async fn upload_image(data_url: &str) -> Result<(String, String), String> {
     let api_key = "KEY";